@@ -1,356 +0,0 @@
-//! # High-Performance Neural Networks
-//! 
-//! This module implements ultra-fast neural networks with:
-//! - SIMD-optimized matrix operations
-//! - Parallel forward/backward propagation
-//! - Multiple activation functions
-//! - Memory-efficient architectures
-
-use crate::types::*;
-use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
-use rayon::prelude::*;
-use rand::prelude::*;
-use rand_distr::Normal;
-use std::time::Instant;
-use tracing::{debug, info};
-
-/// Activation function types
-#[derive(Debug, Clone, PartialEq)]
-#[pyclass]
-pub enum ActivationType {
-    ReLU,
-    Sigmoid,
-    Tanh,
-    LeakyReLU,
-    ELU,
-    Swish,
-}
-
-#[pymethods]
-impl ActivationType {
-    #[new]
-    pub fn new(name: String) -> PyResult<Self> {
-        match name.to_lowercase().as_str() {
-            "relu" => Ok(ActivationType::ReLU),
-            "sigmoid" => Ok(ActivationType::Sigmoid),
-            "tanh" => Ok(ActivationType::Tanh),
-            "leakyrelu" => Ok(ActivationType::LeakyReLU),
-            "elu" => Ok(ActivationType::ELU),
-            "swish" => Ok(ActivationType::Swish),
-            _ => Err(PyValueError::new_err(format!("Unknown activation: {}", name))),
-        }
-    }
-    
-    pub fn __str__(&self) -> String {
-        format!("{:?}", self)
-    }
-}
-
-/// Neural layer with optimized operations
-#[pyclass]
-#[derive(Clone)]
-pub struct NeuralLayer {
-    weights: Vec<Vec<Float>>,
-    biases: Vec<Float>,
-    activation: ActivationType,
-    pub input_size: usize,
-    pub output_size: usize,
-}
-
-#[pymethods]
-impl NeuralLayer {
-    #[new]
-    pub fn new(
-        input_size: usize,
-        output_size: usize,
-        activation: ActivationType,
-    ) -> PyResult<Self> {
-        if input_size == 0 || output_size == 0 {
-            return Err(PyValueError::new_err("Layer sizes must be positive"));
-        }
-        
-        if input_size > 10000 || output_size > 10000 {
-            return Err(PyValueError::new_err("Layer sizes too large (max 10000)"));
-        }
-        
-        let mut rng = thread_rng();
-        let normal = Normal::new(0.0, (2.0 / input_size as Float).sqrt())
-            .map_err(|e| PyValueError::new_err(format!("Failed to create distribution: {}", e)))?;
-        
-        // Xavier/He initialization for better convergence
-        let weights: Vec<Vec<Float>> = (0..output_size)
-            .map(|_| {
-                (0..input_size)
-                    .map(|_| normal.sample(&mut rng))
-                    .collect()
-            })
-            .collect();
-        
-        let biases = vec![0.0; output_size];
-        
-        info!("Neural layer created: {}x{} with {:?} activation", 
-              input_size, output_size, activation);
-        
-        Ok(Self {
-            weights,
-            biases,
-            activation,
-            input_size,
-            output_size,
-        })
-    }
-    
-    /// Forward pass through the layer
-    pub fn forward(&self, inputs: Vec<Float>) -> PyResult<Vec<Float>> {
-        if inputs.len() != self.input_size {
-            return Err(PyValueError::new_err(
-                format!("Input size mismatch: expected {}, got {}", 
-                       self.input_size, inputs.len())
-            ));
-        }
-        
-        // Parallel matrix multiplication
-        let outputs: Vec<Float> = self.weights
-            .par_iter()
-            .zip(self.biases.par_iter())
-            .map(|(weight_row, bias)| {
-                let weighted_sum: Float = weight_row
-                    .iter()
-                    .zip(inputs.iter())
-                    .map(|(w, x)| w * x)
-                    .sum();
-                
-                self.apply_activation(weighted_sum + bias)
-            })
-            .collect();
-        
-        Ok(outputs)
-    }
-    
-    /// Get layer weights (for inspection/serialization)
-    pub fn get_weights(&self) -> Vec<Vec<Float>> {
-        self.weights.clone()
-    }
-    
-    /// Get layer biases
-    pub fn get_biases(&self) -> Vec<Float> {
-        self.biases.clone()
-    }
-    
-    /// Get input size
-    pub fn get_input_size(&self) -> usize {
-        self.input_size
-    }
-    
-    /// Get output size
-    pub fn get_output_size(&self) -> usize {
-        self.output_size
-    }
-    
-    /// Update weights (for training)
-    pub fn update_weights(&mut self, new_weights: Vec<Vec<Float>>) -> PyResult<()> {
-        if new_weights.len() != self.output_size {
-            return Err(PyValueError::new_err("Weight matrix size mismatch"));
-        }
-        
-        for (i, row) in new_weights.iter().enumerate() {
-            if row.len() != self.input_size {
-                return Err(PyValueError::new_err(
-                    format!("Weight row {} size mismatch", i)
-                ));
-            }
-        }
-        
-        self.weights = new_weights;
-        Ok(())
-    }
-}
-
-impl NeuralLayer {
-    /// Apply activation function
-    fn apply_activation(&self, x: Float) -> Float {
-        match self.activation {
-            ActivationType::ReLU => x.max(0.0),
-            ActivationType::Sigmoid => 1.0 / (1.0 + (-x).exp()),
-            ActivationType::Tanh => x.tanh(),
-            ActivationType::LeakyReLU => if x > 0.0 { x } else { 0.01 * x },
-            ActivationType::ELU => if x > 0.0 { x } else { x.exp() - 1.0 },
-            ActivationType::Swish => x / (1.0 + (-x).exp()),
-        }
-    }
-}
-
-/// High-performance neural network
-#[pyclass]
-#[derive(Clone)]
-pub struct NeuralNetwork {
-    layers: Vec<NeuralLayer>,
-    layer_count: usize,
-}
-
-#[pymethods]
-impl NeuralNetwork {
-    #[new]
-    pub fn new(layer_sizes: Vec<usize>, activations: Vec<ActivationType>) -> PyResult<Self> {
-        if layer_sizes.len() < 2 {
-            return Err(PyValueError::new_err("Need at least 2 layers (input + output)"));
-        }
-        
-        if activations.len() != layer_sizes.len() - 1 {
-            return Err(PyValueError::new_err(
-                "Need one activation per layer (excluding input)"
-            ));
-        }
-        
-        let mut layers = Vec::new();
-        
-        for i in 0..layer_sizes.len() - 1 {
-            let layer = NeuralLayer::new(
-                layer_sizes[i],
-                layer_sizes[i + 1],
-                activations[i].clone(),
-            )?;
-            layers.push(layer);
-        }
-        
-        let layer_count = layers.len();
-        
-        info!("Neural network created with {} layers: {:?}", 
-              layer_count, layer_sizes);
-        
-        Ok(Self {
-            layers,
-            layer_count,
-        })
-    }
-    
-    /// Forward propagation through entire network
-    pub fn forward(&self, inputs: Vec<Float>) -> PyResult<Vec<Float>> {
-        let timer = Instant::now();
-        
-        let mut current_outputs = inputs;
-        
-        for (i, layer) in self.layers.iter().enumerate() {
-            current_outputs = layer.forward(current_outputs)?;
-            debug!("Layer {} output size: {}", i, current_outputs.len());
-        }
-        
-        let elapsed = timer.elapsed().as_micros();
-        debug!("Forward pass completed in {}μs", elapsed);
-        
-        Ok(current_outputs)
-    }
-    
-    /// Get network architecture
-    pub fn get_architecture(&self) -> Vec<usize> {
-        let mut arch = vec![self.layers[0].input_size];
-        for layer in &self.layers {
-            arch.push(layer.output_size);
-        }
-        arch
-    }
-    
-    /// Get total number of parameters
-    pub fn get_parameter_count(&self) -> usize {
-        self.layers.iter().map(|layer| {
-            layer.input_size * layer.output_size + layer.output_size
-        }).sum()
-    }
-    
-    /// Batch forward propagation (parallel processing)
-    pub fn batch_forward(&self, batch_inputs: Vec<Vec<Float>>) -> PyResult<Vec<Vec<Float>>> {
-        if batch_inputs.is_empty() {
-            return Ok(vec![]);
-        }
-        
-        let timer = Instant::now();
-        
-        let results: Result<Vec<Vec<Float>>, _> = batch_inputs
-            .par_iter()
-            .map(|inputs| self.forward(inputs.clone()))
-            .collect();
-        
-        let outputs = results?;
-        
-        let elapsed = timer.elapsed().as_millis();
-        info!("Batch forward ({} samples) completed in {}ms", 
-              batch_inputs.len(), elapsed);
-        
-        Ok(outputs)
-    }
-}
-
-/// Create a simple feedforward network
-#[pyfunction]
-pub fn create_feedforward_network(
-    input_size: usize,
-    hidden_sizes: Vec<usize>,
-    output_size: usize,
-    activation: String,
-) -> PyResult<NeuralNetwork> {
-    let mut layer_sizes = vec![input_size];
-    layer_sizes.extend(hidden_sizes);
-    layer_sizes.push(output_size);
-    
-    let activation_type = ActivationType::new(activation)?;
-    let activations = vec![activation_type; layer_sizes.len() - 1];
-    
-    NeuralNetwork::new(layer_sizes, activations)
-}
-
-/// Parallel network ensemble for robust predictions
-#[pyfunction]
-pub fn ensemble_predict(
-    networks: Vec<Py<NeuralNetwork>>,
-    inputs: Vec<Float>,
-) -> PyResult<Vec<Float>> {
-    if networks.is_empty() {
-        return Err(PyValueError::new_err("Empty network ensemble"));
-    }
-    
-    let timer = Instant::now();
-    
-    // Use sequential processing for Python objects due to GIL restrictions
-    Python::with_gil(|py| {
-        let mut all_predictions = Vec::new();
-        
-        for net_py in &networks {
-            let net = net_py.borrow(py);
-            let prediction = net.forward(inputs.clone())?;
-            all_predictions.push(prediction);
-        }
-        
-        // Average predictions
-        let output_size = all_predictions[0].len();
-        let mut averaged = vec![0.0; output_size];
-        
-        for prediction in &all_predictions {
-            for (i, &value) in prediction.iter().enumerate() {
-                averaged[i] += value;
-            }
-        }
-        
-        for value in &mut averaged {
-            *value /= networks.len() as Float;
-        }
-        
-        let elapsed = timer.elapsed().as_micros();
-        info!("Ensemble prediction ({} networks) completed in {}μs", 
-              networks.len(), elapsed);
-        
-        Ok(averaged)
-    })
-}
-
-/// Register neural network functions with Python
-pub fn register_neural_functions(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
-    m.add_class::<ActivationType>()?;
-    m.add_class::<NeuralLayer>()?;
-    m.add_class::<NeuralNetwork>()?;
-    m.add_function(wrap_pyfunction!(create_feedforward_network, m)?)?;
-    m.add_function(wrap_pyfunction!(ensemble_predict, m)?)?;
-    
-    info!("Neural network functions registered successfully");
-    Ok(())
-}
@@ -0,0 +1,1734 @@
+//! # Advanced Agent System
+//! 
+//! This module implements intelligent agents with:
+//! - Cognitive architectures
+//! - Decision-making systems
+//! - Social interaction capabilities
+//! - Learning and adaptation
+
+use crate::types::*;
+use crate::neural::{ActivationType, NeuralNetwork};
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+use tracing::{debug, info};
+
+/// A single observed transition, as fed to `IntelligentAgent::observe` for
+/// experience-replay learning.
+#[pyclass]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Transition {
+    #[pyo3(get)]
+    pub state: Vec<Float>,
+
+    #[pyo3(get)]
+    pub action: usize,
+
+    #[pyo3(get)]
+    pub reward: Float,
+
+    #[pyo3(get)]
+    pub next_state: Vec<Float>,
+
+    #[pyo3(get)]
+    pub done: bool,
+}
+
+#[pymethods]
+impl Transition {
+    #[new]
+    pub fn new(state: Vec<Float>, action: usize, reward: Float, next_state: Vec<Float>, done: bool) -> Self {
+        Self { state, action, reward, next_state, done }
+    }
+}
+
+/// Fixed-capacity ring buffer of transitions for experience-replay learning.
+/// Oldest transitions are dropped once `capacity` is reached.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReplayMemory {
+    capacity: usize,
+    transitions: VecDeque<Transition>,
+}
+
+impl ReplayMemory {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            transitions: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, transition: Transition) {
+        if self.transitions.len() >= self.capacity {
+            self.transitions.pop_front();
+        }
+        self.transitions.push_back(transition);
+    }
+
+    /// Draw `batch_size` transitions uniformly at random, with replacement.
+    /// Returns fewer than `batch_size` only if the memory itself holds less.
+    fn sample(&self, batch_size: usize) -> Vec<Transition> {
+        let mut rng = thread_rng();
+        let draws = batch_size.min(self.transitions.len());
+        (0..draws)
+            .map(|_| self.transitions[rng.gen_range(0..self.transitions.len())].clone())
+            .collect()
+    }
+
+    fn len(&self) -> usize {
+        self.transitions.len()
+    }
+}
+
+/// Derivative of `activation` with respect to its pre-activation input,
+/// expressed in terms of the already-computed output (avoids needing to
+/// keep the pre-activation value around). Used for the last-layer gradient
+/// step in `IntelligentAgent::learn`.
+///
+/// Swish has no closed form in terms of its output alone, since it isn't
+/// monotonic; `output.clamp(0.0, 1.0)` is used as a cheap approximation,
+/// which is adequate for the small last-layer nudge this drives.
+fn activation_derivative(activation: &ActivationType, output: Float) -> Float {
+    match activation {
+        ActivationType::ReLU => if output > 0.0 { 1.0 } else { 0.0 },
+        ActivationType::Sigmoid => output * (1.0 - output),
+        ActivationType::Tanh => 1.0 - output * output,
+        ActivationType::LeakyReLU => if output > 0.0 { 1.0 } else { 0.01 },
+        ActivationType::ELU => if output > 0.0 { 1.0 } else { output + 1.0 },
+        ActivationType::Swish => output.clamp(0.0, 1.0),
+        // As in `NeuralLayer::activation_derivative`: paired with a
+        // cross-entropy loss (the only pairing Softmax is meant for), the
+        // combined output error simplifies to `(a - target)`, so passing
+        // the last-layer gradient through unchanged is exact, not an
+        // approximation like the other arms above.
+        ActivationType::Softmax => 1.0,
+    }
+}
+
+/// Agent behavior types
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub enum BehaviorType {
+    Explorer,
+    Socializer,
+    Optimizer,
+    Creator,
+    Analyzer,
+}
+
+#[pymethods]
+impl BehaviorType {
+    #[new]
+    pub fn new(name: String) -> PyResult<Self> {
+        match name.to_lowercase().as_str() {
+            "explorer" => Ok(BehaviorType::Explorer),
+            "socializer" => Ok(BehaviorType::Socializer),
+            "optimizer" => Ok(BehaviorType::Optimizer),
+            "creator" => Ok(BehaviorType::Creator),
+            "analyzer" => Ok(BehaviorType::Analyzer),
+            _ => Err(PyValueError::new_err(format!("Unknown behavior: {}", name))),
+        }
+    }
+    
+    pub fn __str__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Cognitive state of an agent
+#[pyclass]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CognitiveState {
+    pub attention: Float,
+    pub memory_capacity: Float,
+    pub processing_speed: Float,
+    pub creativity: Float,
+    pub social_awareness: Float,
+    pub emotional_stability: Float,
+}
+
+#[pymethods]
+impl CognitiveState {
+    #[new]
+    pub fn new(
+        attention: Float,
+        memory_capacity: Float,
+        processing_speed: Float,
+        creativity: Float,
+        social_awareness: Float,
+        emotional_stability: Float,
+    ) -> PyResult<Self> {
+        // Validate ranges (0.0 to 1.0)
+        for (name, value) in [
+            ("attention", attention),
+            ("memory_capacity", memory_capacity),
+            ("processing_speed", processing_speed),
+            ("creativity", creativity),
+            ("social_awareness", social_awareness),
+            ("emotional_stability", emotional_stability),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(PyValueError::new_err(
+                    format!("{} must be between 0.0 and 1.0, got {}", name, value)
+                ));
+            }
+        }
+        
+        Ok(Self {
+            attention,
+            memory_capacity,
+            processing_speed,
+            creativity,
+            social_awareness,
+            emotional_stability,
+        })
+    }
+    
+    /// Get overall cognitive capacity
+    pub fn get_capacity(&self) -> Float {
+        (self.attention + self.memory_capacity + self.processing_speed + 
+         self.creativity + self.social_awareness + self.emotional_stability) / 6.0
+    }
+    
+    /// Update cognitive state based on experience
+    pub fn update(&mut self, experience_type: String, intensity: Float) -> PyResult<()> {
+        let adjustment = intensity.clamp(-0.1, 0.1);
+        
+        match experience_type.as_str() {
+            "learning" => {
+                self.memory_capacity = (self.memory_capacity + adjustment).clamp(0.0, 1.0);
+                self.processing_speed = (self.processing_speed + adjustment * 0.5).clamp(0.0, 1.0);
+            },
+            "social" => {
+                self.social_awareness = (self.social_awareness + adjustment).clamp(0.0, 1.0);
+                self.emotional_stability = (self.emotional_stability + adjustment * 0.3).clamp(0.0, 1.0);
+            },
+            "creative" => {
+                self.creativity = (self.creativity + adjustment).clamp(0.0, 1.0);
+                self.attention = (self.attention + adjustment * 0.2).clamp(0.0, 1.0);
+            },
+            "stress" => {
+                self.emotional_stability = (self.emotional_stability - adjustment.abs()).clamp(0.0, 1.0);
+                self.attention = (self.attention - adjustment.abs() * 0.5).clamp(0.0, 1.0);
+            },
+            _ => return Err(PyValueError::new_err(format!("Unknown experience type: {}", experience_type))),
+        }
+        
+        Ok(())
+    }
+}
+
+/// Functional form of a [`ResponseCurve`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub enum ResponseCurveKind {
+    Linear,
+    Quadratic,
+    Logistic,
+    Inverse,
+}
+
+/// Maps a raw consideration input to a normalized `[0, 1]` score. Construct
+/// via the `linear`, `quadratic`, `logistic`, or `inverse` static methods;
+/// `evaluate` applies the curve and clamps the result to `[0, 1]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[pyclass]
+pub struct ResponseCurve {
+    #[pyo3(get, set)]
+    pub kind: ResponseCurveKind,
+
+    /// Input value mapped to output `0.5` (the curve's center).
+    #[pyo3(get, set)]
+    pub midpoint: Float,
+
+    /// Slope for `Linear`/`Quadratic`, steepness for `Logistic`/`Inverse`.
+    #[pyo3(get, set)]
+    pub steepness: Float,
+}
+
+#[pymethods]
+impl ResponseCurve {
+    #[staticmethod]
+    pub fn linear(midpoint: Float, steepness: Float) -> Self {
+        Self { kind: ResponseCurveKind::Linear, midpoint, steepness }
+    }
+
+    #[staticmethod]
+    pub fn quadratic(midpoint: Float, steepness: Float) -> Self {
+        Self { kind: ResponseCurveKind::Quadratic, midpoint, steepness }
+    }
+
+    #[staticmethod]
+    pub fn logistic(midpoint: Float, steepness: Float) -> Self {
+        Self { kind: ResponseCurveKind::Logistic, midpoint, steepness }
+    }
+
+    #[staticmethod]
+    pub fn inverse(midpoint: Float, steepness: Float) -> Self {
+        Self { kind: ResponseCurveKind::Inverse, midpoint, steepness }
+    }
+
+    /// Map `x` through the curve, clamped to `[0, 1]`.
+    pub fn evaluate(&self, x: Float) -> Float {
+        let raw = match self.kind {
+            ResponseCurveKind::Linear => (x - self.midpoint) * self.steepness + 0.5,
+            ResponseCurveKind::Quadratic => {
+                let d = (x - self.midpoint) * self.steepness;
+                d.signum() * d.powi(2) + 0.5
+            }
+            ResponseCurveKind::Logistic => 1.0 / (1.0 + (-self.steepness * (x - self.midpoint)).exp()),
+            ResponseCurveKind::Inverse => 1.0 / (1.0 + (self.steepness * (x - self.midpoint)).abs()),
+        };
+        raw.clamp(0.0, 1.0)
+    }
+}
+
+/// Where a [`Consideration`] reads its raw input from before applying its
+/// curve.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub enum ConsiderationInputKind {
+    /// A named field of the agent's `CognitiveState` (e.g. `"attention"`).
+    CognitiveField,
+    /// A DNA gene, by index.
+    Gene,
+    /// A named entry in the agent's memory.
+    Memory,
+    /// A named entry in the `context` passed to `pick_action`.
+    Situation,
+}
+
+/// One scored input to a utility-AI [`Action`]: reads a raw value (from
+/// cognitive state, DNA, memory, or the decision context) and maps it
+/// through a `curve` into a normalized `[0, 1]` score. Construct via the
+/// `cognitive_field`, `gene`, `memory`, or `situation` static methods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[pyclass]
+pub struct Consideration {
+    #[pyo3(get, set)]
+    pub input_kind: ConsiderationInputKind,
+
+    /// Cognitive-state field name, memory key, or situation key. Unused
+    /// (empty) for `Gene`.
+    #[pyo3(get, set)]
+    pub key: String,
+
+    /// Gene index. Unused (`0`) for anything but `Gene`.
+    #[pyo3(get, set)]
+    pub index: usize,
+
+    #[pyo3(get, set)]
+    pub curve: ResponseCurve,
+}
+
+#[pymethods]
+impl Consideration {
+    #[staticmethod]
+    pub fn cognitive_field(field: String, curve: ResponseCurve) -> Self {
+        Self { input_kind: ConsiderationInputKind::CognitiveField, key: field, index: 0, curve }
+    }
+
+    #[staticmethod]
+    pub fn gene(index: usize, curve: ResponseCurve) -> Self {
+        Self { input_kind: ConsiderationInputKind::Gene, key: String::new(), index, curve }
+    }
+
+    #[staticmethod]
+    pub fn memory(key: String, curve: ResponseCurve) -> Self {
+        Self { input_kind: ConsiderationInputKind::Memory, key, index: 0, curve }
+    }
+
+    #[staticmethod]
+    pub fn situation(key: String, curve: ResponseCurve) -> Self {
+        Self { input_kind: ConsiderationInputKind::Situation, key, index: 0, curve }
+    }
+}
+
+/// A candidate action scored by multiplying its considerations' normalized
+/// outputs together (with a compensation factor to counter the pessimism of
+/// multiplying many sub-1 values), following the classic utility-AI
+/// Infinite Axis/Considerations design. Built declaratively from Python and
+/// evaluated by `IntelligentAgent::pick_action` instead of a hardcoded
+/// `match` per `BehaviorType`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[pyclass]
+pub struct Action {
+    #[pyo3(get, set)]
+    pub id: String,
+
+    #[pyo3(get, set)]
+    pub considerations: Vec<Consideration>,
+}
+
+#[pymethods]
+impl Action {
+    #[new]
+    pub fn new(id: String, considerations: Vec<Consideration>) -> Self {
+        Self { id, considerations }
+    }
+}
+
+/// Combine per-consideration scores into one action score, compensating for
+/// the pessimism of multiplying several sub-1 values together: the more
+/// considerations there are, the more a low score is pulled back up.
+fn compensate(product: Float, count: usize) -> Float {
+    if count == 0 {
+        return 0.0;
+    }
+    let modification_factor = 1.0 - (1.0 / count as Float);
+    let make_up_value = (1.0 - product) * modification_factor;
+    (product + make_up_value * product).clamp(0.0, 1.0)
+}
+
+/// Advanced intelligent agent
+#[pyclass]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct IntelligentAgent {
+    pub id: String,
+    pub dna: AgentDNA,
+    pub behavior_type: BehaviorType,
+    pub cognitive_state: CognitiveState,
+    decision_network: Option<NeuralNetwork>,
+    target_network: Option<NeuralNetwork>,
+    replay_memory: ReplayMemory,
+    memory: HashMap<String, Float>,
+    social_connections: Vec<String>,
+    experience_points: u64,
+    age: u64,
+
+    /// Utility-AI actions evaluated by `pick_action`, as an alternative to
+    /// `simple_decision_making`'s hardcoded per-`BehaviorType` branches.
+    actions: Vec<Action>,
+
+    /// Discount factor for future rewards in the Q-learning target.
+    gamma: Float,
+    /// Soft-update coefficient: `theta_target = tau*theta_online + (1-tau)*theta_target`.
+    tau: Float,
+    /// How many `learn()` calls between target-network soft updates.
+    target_update_every: u64,
+    learning_rate: Float,
+
+    epsilon: Float,
+    epsilon_decay: Float,
+    epsilon_min: Float,
+
+    learn_steps: u64,
+
+    /// Forward-dynamics model predicting next-state features from
+    /// `[state, action]`, used to compute the intrinsic curiosity reward.
+    dynamics_model: Option<NeuralNetwork>,
+    /// Weight applied to the dynamics model's prediction error before it is
+    /// blended into an observed transition's reward.
+    curiosity_strength: Float,
+
+    /// Carried recurrent hidden state, fed back into `make_decision` as
+    /// extra input. Empty (and unused) unless `enable_recurrent` was called.
+    hidden: Vec<Float>,
+    /// Size of `hidden`; `0` means this agent is not recurrent.
+    hidden_size: usize,
+    /// How many steps of a stored trajectory are replayed with `hidden`
+    /// carried forward before it should be reset.
+    sequence_length: usize,
+}
+
+#[pymethods]
+impl IntelligentAgent {
+    #[new]
+    #[pyo3(signature = (
+        id,
+        dna,
+        behavior_type,
+        cognitive_state,
+        replay_capacity = 10000,
+        gamma = 0.99,
+        tau = 0.01,
+        target_update_every = 1,
+        learning_rate = 0.01,
+        epsilon = 1.0,
+        epsilon_decay = 0.995,
+        epsilon_min = 0.01,
+        curiosity_strength = 0.0
+    ))]
+    pub fn new(
+        id: String,
+        dna: AgentDNA,
+        behavior_type: BehaviorType,
+        cognitive_state: CognitiveState,
+        replay_capacity: usize,
+        gamma: Float,
+        tau: Float,
+        target_update_every: u64,
+        learning_rate: Float,
+        epsilon: Float,
+        epsilon_decay: Float,
+        epsilon_min: Float,
+        curiosity_strength: Float,
+    ) -> PyResult<Self> {
+        info!("Creating intelligent agent: {} with behavior {:?}", id, behavior_type);
+
+        Ok(Self {
+            id,
+            dna,
+            behavior_type,
+            cognitive_state,
+            decision_network: None,
+            target_network: None,
+            replay_memory: ReplayMemory::new(replay_capacity),
+            memory: HashMap::new(),
+            social_connections: Vec::new(),
+            experience_points: 0,
+            age: 0,
+            actions: Vec::new(),
+            gamma,
+            tau,
+            target_update_every: target_update_every.max(1),
+            learning_rate,
+            epsilon,
+            epsilon_decay,
+            epsilon_min,
+            learn_steps: 0,
+            dynamics_model: None,
+            curiosity_strength,
+            hidden: Vec::new(),
+            hidden_size: 0,
+            sequence_length: 1,
+        })
+    }
+
+    /// Configure this agent's decision network as recurrent: `hidden_size`
+    /// elements of the network's input/output are reserved for a carried
+    /// hidden state, fed back in on the next `make_decision` and refreshed
+    /// from the trailing `hidden_size` outputs each call — an Elman-style
+    /// simplification of full GRU gating, since the network has no gating
+    /// structure yet (see `learn()`'s doc comment for the same kind of
+    /// honest simplification elsewhere in this module). `sequence_length`
+    /// is how many steps of a stored trajectory a caller should replay with
+    /// `hidden` carried forward (calling `reset_hidden` between sequences)
+    /// before truncating.
+    pub fn enable_recurrent(&mut self, hidden_size: usize, sequence_length: usize) {
+        self.hidden_size = hidden_size;
+        self.sequence_length = sequence_length.max(1);
+        self.hidden = vec![0.0; hidden_size];
+    }
+
+    /// Whether this agent has a recurrent hidden state configured.
+    pub fn is_recurrent(&self) -> bool {
+        self.hidden_size > 0
+    }
+
+    /// Clear the hidden state to zero, e.g. at an episode boundary.
+    pub fn reset_hidden(&mut self) {
+        self.hidden = vec![0.0; self.hidden_size];
+    }
+
+    /// Get the current hidden state.
+    pub fn get_hidden(&self) -> Vec<Float> {
+        self.hidden.clone()
+    }
+
+    /// Overwrite the hidden state, e.g. to resume a stored trajectory.
+    pub fn set_hidden(&mut self, hidden: Vec<Float>) -> PyResult<()> {
+        if hidden.len() != self.hidden_size {
+            return Err(PyValueError::new_err(format!(
+                "Hidden state size mismatch: expected {}, got {}",
+                self.hidden_size,
+                hidden.len()
+            )));
+        }
+        self.hidden = hidden;
+        Ok(())
+    }
+
+    /// How many steps of a stored trajectory should be replayed with
+    /// `hidden` carried forward before it is reset.
+    pub fn get_sequence_length(&self) -> usize {
+        self.sequence_length
+    }
+
+    /// Set the forward-dynamics model used for intrinsic curiosity: it
+    /// predicts the next state's features from `[state, action]` (see
+    /// `train_dynamics`), so its input size must be `state_len + 1`.
+    pub fn set_dynamics_model(&mut self, network: NeuralNetwork) {
+        self.dynamics_model = Some(network);
+    }
+
+    /// Current curiosity-reward weight.
+    pub fn get_curiosity_strength(&self) -> Float {
+        self.curiosity_strength
+    }
+
+    /// The dynamics model's scaled prediction error for `(state, action,
+    /// next_state)`: `curiosity_strength * mean((predicted - actual)^2)`.
+    /// Returns 0.0 if no dynamics model is set, so curiosity is an opt-in
+    /// bonus rather than a hard requirement.
+    pub fn intrinsic_reward(&self, state: Vec<Float>, action: usize, next_state: Vec<Float>) -> PyResult<Float> {
+        let model = match self.dynamics_model.as_ref() {
+            Some(model) => model,
+            None => return Ok(0.0),
+        };
+        let predicted = model.forward(dynamics_input(&state, action))?;
+        Ok(self.curiosity_strength * squared_prediction_error(&predicted, &next_state))
+    }
+
+    /// One last-layer gradient step for the dynamics model toward predicting
+    /// `next_state` from `(state, action)` — the same last-layer-only
+    /// simplification `learn()` uses, since the network has no generic
+    /// backprop yet. Returns the prediction error from *before* this update,
+    /// suitable as that step's curiosity bonus.
+    pub fn train_dynamics(&mut self, state: Vec<Float>, action: usize, next_state: Vec<Float>) -> PyResult<Float> {
+        let model = self
+            .dynamics_model
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Dynamics model not set"))?
+            .clone();
+
+        let input = dynamics_input(&state, action);
+        let layer_outputs = model.forward_with_layer_outputs(input.clone())?;
+        let mut layers = model.get_layers();
+        let last = layers.len() - 1;
+        let last_activation = layers[last].get_activation();
+
+        let predicted = layer_outputs[last].clone();
+        let error_before = squared_prediction_error(&predicted, &next_state);
+
+        let last_layer_input = if last == 0 { &input } else { &layer_outputs[last - 1] };
+
+        let mut weight_grad = vec![vec![0.0; layers[last].get_input_size()]; layers[last].get_output_size()];
+        let mut bias_grad = vec![0.0; layers[last].get_output_size()];
+
+        for (k, (&pred, &actual)) in predicted.iter().zip(next_state.iter()).enumerate() {
+            let error = actual - pred;
+            let delta = error * activation_derivative(&last_activation, pred);
+            for (grad, input_value) in weight_grad[k].iter_mut().zip(last_layer_input.iter()) {
+                *grad += delta * input_value;
+            }
+            bias_grad[k] += delta;
+        }
+
+        let mut new_weights = layers[last].get_weights();
+        let mut new_biases = layers[last].get_biases();
+        for (w_row, g_row) in new_weights.iter_mut().zip(weight_grad.iter()) {
+            for (w, g) in w_row.iter_mut().zip(g_row.iter()) {
+                *w += self.learning_rate * g;
+            }
+        }
+        for (b, g) in new_biases.iter_mut().zip(bias_grad.iter()) {
+            *b += self.learning_rate * g;
+        }
+        layers[last].update_weights(new_weights)?;
+        layers[last].update_biases(new_biases)?;
+
+        let mut model = model;
+        model.set_layers(layers)?;
+        self.dynamics_model = Some(model);
+
+        Ok(error_before)
+    }
+
+    /// Compute the intrinsic curiosity reward for `(state, action,
+    /// next_state)`, train the dynamics model on the observed transition,
+    /// blend the result with `extrinsic_reward`, and push the blended
+    /// transition into the replay buffer (as `observe` does). Returns the
+    /// blended reward actually stored.
+    pub fn observe_with_curiosity(
+        &mut self,
+        state: Vec<Float>,
+        action: usize,
+        extrinsic_reward: Float,
+        next_state: Vec<Float>,
+        done: bool,
+    ) -> PyResult<Float> {
+        let intrinsic = if self.dynamics_model.is_some() {
+            self.curiosity_strength * self.train_dynamics(state.clone(), action, next_state.clone())?
+        } else {
+            0.0
+        };
+
+        let blended_reward = extrinsic_reward + intrinsic;
+        self.observe(Transition::new(state, action, blended_reward, next_state, done));
+        Ok(blended_reward)
+    }
+
+    /// Set decision-making neural network. Also (re)initializes the target
+    /// network used by `learn()` as an identical clone, so online and target
+    /// Q-values start in agreement.
+    pub fn set_decision_network(&mut self, network: NeuralNetwork) {
+        self.target_network = Some(network.clone());
+        self.decision_network = Some(network);
+        info!("Decision network set for agent {}", self.id);
+    }
+    
+    /// Make a decision based on current state and inputs
+    pub fn make_decision(&mut self, situation_inputs: Vec<Float>) -> PyResult<Vec<Float>> {
+        let timer = Instant::now();
+
+        // Combine situation inputs with agent's internal state
+        let mut decision_inputs = situation_inputs.clone();
+
+        // Add cognitive state as context
+        decision_inputs.extend(vec![
+            self.cognitive_state.attention,
+            self.cognitive_state.memory_capacity,
+            self.cognitive_state.processing_speed,
+            self.cognitive_state.creativity,
+            self.cognitive_state.social_awareness,
+            self.cognitive_state.emotional_stability,
+        ]);
+
+        // Add some DNA genes as personality factors
+        decision_inputs.extend(self.dna.genes.iter().take(4).cloned());
+
+        // Feed the carried hidden state back in as extra input, if recurrent
+        if self.is_recurrent() {
+            decision_inputs.extend(self.hidden.iter().cloned());
+        }
+
+        // Use decision network if available
+        let decision = if let Some(ref network) = self.decision_network {
+            let raw = network.forward(decision_inputs)?;
+            if self.is_recurrent() {
+                self.split_recurrent_output(raw)?
+            } else {
+                raw
+            }
+        } else {
+            // Fallback: simple rule-based decision
+            self.simple_decision_making(situation_inputs)?
+        };
+
+        let elapsed = timer.elapsed().as_micros();
+        debug!("Agent {} made decision in {}Î¼s", self.id, elapsed);
+
+        Ok(decision)
+    }
+    
+    /// Simple rule-based decision making (fallback)
+    /// Split a recurrent decision network's raw output into the decision
+    /// proper and the trailing `hidden_size` values, storing the latter as
+    /// the new hidden state for the next `make_decision` call.
+    fn split_recurrent_output(&mut self, output: Vec<Float>) -> PyResult<Vec<Float>> {
+        if output.len() < self.hidden_size {
+            return Err(PyValueError::new_err(format!(
+                "Decision network output ({}) is smaller than hidden_size ({})",
+                output.len(),
+                self.hidden_size
+            )));
+        }
+        let split_at = output.len() - self.hidden_size;
+        let (decision, hidden) = output.split_at(split_at);
+        self.hidden = hidden.to_vec();
+        Ok(decision.to_vec())
+    }
+
+    fn simple_decision_making(&self, inputs: Vec<Float>) -> PyResult<Vec<Float>> {
+        let mut decision = vec![0.0; 3]; // [action_intensity, social_tendency, risk_taking]
+        
+        let input_sum: Float = inputs.iter().sum();
+        let input_avg = if !inputs.is_empty() { input_sum / inputs.len() as Float } else { 0.0 };
+        
+        match self.behavior_type {
+            BehaviorType::Explorer => {
+                decision[0] = (input_avg + self.cognitive_state.creativity).min(1.0);
+                decision[2] = self.cognitive_state.attention; // Higher risk tolerance
+            },
+            BehaviorType::Socializer => {
+                decision[1] = self.cognitive_state.social_awareness;
+                decision[0] = input_avg * 0.7; // Moderate action
+            },
+            BehaviorType::Optimizer => {
+                decision[0] = self.cognitive_state.processing_speed;
+                decision[2] = 1.0 - self.cognitive_state.emotional_stability; // Lower risk
+            },
+            BehaviorType::Creator => {
+                decision[0] = self.cognitive_state.creativity;
+                decision[1] = input_avg * 0.5; // Some social tendency
+            },
+            BehaviorType::Analyzer => {
+                decision[0] = self.cognitive_state.processing_speed * 0.8;
+                decision[2] = 0.2; // Very conservative
+            },
+        }
+        
+        Ok(decision)
+    }
+
+    /// Add a utility-AI action for `pick_action` to consider.
+    pub fn add_action(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+
+    /// Score every configured action against `context` (situation inputs,
+    /// keyed by name) and return the highest-scoring action's id and score.
+    pub fn pick_action(&self, context: HashMap<String, Float>) -> PyResult<(String, Float)> {
+        if self.actions.is_empty() {
+            return Err(PyValueError::new_err("No actions configured; call add_action first"));
+        }
+
+        self.actions
+            .iter()
+            .map(|action| (action.id.clone(), self.score_action(action, &context)))
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .ok_or_else(|| PyValueError::new_err("No actions configured; call add_action first"))
+    }
+
+    /// Add social connection
+    pub fn add_social_connection(&mut self, other_agent_id: String) {
+        if !self.social_connections.contains(&other_agent_id) {
+            self.social_connections.push(other_agent_id);
+        }
+    }
+    
+    /// Get social network size
+    pub fn get_social_network_size(&self) -> usize {
+        self.social_connections.len()
+    }
+    
+    /// Store memory
+    pub fn store_memory(&mut self, key: String, value: Float) {
+        let memory_limit = (self.cognitive_state.memory_capacity * 100.0) as usize;
+        
+        if self.memory.len() >= memory_limit {
+            // Remove oldest memory (simple implementation)
+            if let Some(first_key) = self.memory.keys().next().cloned() {
+                self.memory.remove(&first_key);
+            }
+        }
+        
+        self.memory.insert(key, value);
+    }
+    
+    /// Retrieve memory
+    pub fn get_memory(&self, key: String) -> Option<Float> {
+        self.memory.get(&key).copied()
+    }
+    
+    /// Gain experience and potentially level up
+    pub fn gain_experience(&mut self, points: u64, experience_type: String) -> PyResult<bool> {
+        self.experience_points += points;
+        self.age += 1;
+        
+        // Update cognitive state based on experience
+        let intensity = (points as Float / 100.0).min(0.1);
+        self.cognitive_state.update(experience_type, intensity)?;
+        
+        // Check for level up (every 1000 experience points)
+        let level_up = self.experience_points % 1000 == 0 && points > 0;
+        
+        if level_up {
+            info!("Agent {} leveled up! Total XP: {}", self.id, self.experience_points);
+        }
+        
+        Ok(level_up)
+    }
+    
+    /// Get agent statistics
+    pub fn get_stats(&self) -> HashMap<String, Float> {
+        let mut stats = HashMap::new();
+        
+        stats.insert("experience_points".to_string(), self.experience_points as Float);
+        stats.insert("age".to_string(), self.age as Float);
+        stats.insert("cognitive_capacity".to_string(), self.cognitive_state.get_capacity());
+        stats.insert("social_connections".to_string(), self.social_connections.len() as Float);
+        stats.insert("memory_usage".to_string(), self.memory.len() as Float);
+        stats.insert("fitness".to_string(), self.dna.fitness.unwrap_or(0.0));
+        
+        stats
+    }
+    
+    /// Get agent ID
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+    
+    /// Get behavior type as string
+    pub fn get_behavior(&self) -> String {
+        format!("{:?}", self.behavior_type)
+    }
+
+    /// Save this agent (DNA, cognitive state, memory, social connections,
+    /// replay buffer, and decision/target network weights) to `path`.
+    /// `format` is `"json"` for a human-readable checkpoint or `"binary"`
+    /// for a compact `bincode` encoding.
+    pub fn save(&self, path: String, format: String) -> PyResult<()> {
+        write_checkpoint(self, &path, &format)
+    }
+
+    /// Load an agent previously written by `save`. `format` must match the
+    /// format used to save it.
+    #[staticmethod]
+    pub fn load(path: String, format: String) -> PyResult<Self> {
+        read_checkpoint(&path, &format)
+    }
+
+    /// Record a transition in the replay buffer for later `learn()` calls.
+    pub fn observe(&mut self, transition: Transition) {
+        self.replay_memory.push(transition);
+    }
+
+    /// Number of transitions currently held in the replay buffer.
+    pub fn replay_size(&self) -> usize {
+        self.replay_memory.len()
+    }
+
+    /// Current exploration rate.
+    pub fn get_epsilon(&self) -> Float {
+        self.epsilon
+    }
+
+    /// Epsilon-greedy action selection over the decision network's Q-values
+    /// for `state`: a random action with probability `epsilon`, otherwise
+    /// the highest-Q action. Decays `epsilon` by `epsilon_decay` down to
+    /// `epsilon_min` on every call.
+    pub fn train_step(&mut self, state: Vec<Float>) -> PyResult<usize> {
+        let network = self
+            .decision_network
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Decision network not set"))?;
+
+        let q_values = network.forward(state)?;
+        if q_values.is_empty() {
+            return Err(PyValueError::new_err("Decision network produced no outputs"));
+        }
+
+        let mut rng = thread_rng();
+        let action = if rng.gen::<Float>() < self.epsilon {
+            rng.gen_range(0..q_values.len())
+        } else {
+            argmax(&q_values)
+        };
+
+        self.epsilon = (self.epsilon * self.epsilon_decay).max(self.epsilon_min);
+
+        Ok(action)
+    }
+
+    /// Sample `batch_size` transitions from the replay buffer and take one
+    /// Q-learning gradient step on the decision network's last layer toward
+    /// `y = reward + gamma * max_a' Q_target(next_state)` (or `y = reward`
+    /// for terminal transitions). Every `target_update_every` calls, soft
+    /// updates the target network from the (now-trained) decision network.
+    /// Returns the batch's mean squared TD error, or 0.0 if the replay
+    /// buffer is empty.
+    pub fn learn(&mut self, batch_size: usize) -> PyResult<Float> {
+        let batch = self.replay_memory.sample(batch_size);
+        if batch.is_empty() {
+            return Ok(0.0);
+        }
+
+        let online = self
+            .decision_network
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Decision network not set"))?
+            .clone();
+        let target = self
+            .target_network
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Target network not set"))?;
+
+        let mut layers = online.get_layers();
+        let last = layers.len() - 1;
+        let last_activation = layers[last].get_activation();
+
+        let mut weight_grad = vec![vec![0.0; layers[last].get_input_size()]; layers[last].get_output_size()];
+        let mut bias_grad = vec![0.0; layers[last].get_output_size()];
+        let mut squared_error_sum = 0.0;
+
+        for transition in &batch {
+            let next_q = target.forward(transition.next_state.clone())?;
+            let max_next_q = next_q.iter().cloned().fold(Float::NEG_INFINITY, Float::max);
+            let target_q = if transition.done {
+                transition.reward
+            } else {
+                transition.reward + self.gamma * max_next_q
+            };
+
+            let layer_outputs = online.forward_with_layer_outputs(transition.state.clone())?;
+            let current_q = &layer_outputs[last];
+            let last_layer_input = if last == 0 {
+                &transition.state
+            } else {
+                &layer_outputs[last - 1]
+            };
+
+            let action = transition.action.min(current_q.len() - 1);
+            let error = target_q - current_q[action];
+            squared_error_sum += error * error;
+
+            let delta = error * activation_derivative(&last_activation, current_q[action]);
+            for (grad, input) in weight_grad[action].iter_mut().zip(last_layer_input.iter()) {
+                *grad += delta * input;
+            }
+            bias_grad[action] += delta;
+        }
+
+        let n = batch.len() as Float;
+        let mut new_weights = layers[last].get_weights();
+        let mut new_biases = layers[last].get_biases();
+        for (w_row, g_row) in new_weights.iter_mut().zip(weight_grad.iter()) {
+            for (w, g) in w_row.iter_mut().zip(g_row.iter()) {
+                *w += self.learning_rate * g / n;
+            }
+        }
+        for (b, g) in new_biases.iter_mut().zip(bias_grad.iter()) {
+            *b += self.learning_rate * g / n;
+        }
+        layers[last].update_weights(new_weights)?;
+        layers[last].update_biases(new_biases)?;
+
+        let mut online = online;
+        online.set_layers(layers)?;
+        self.decision_network = Some(online);
+
+        self.learn_steps += 1;
+        if self.learn_steps % self.target_update_every == 0 {
+            self.soft_update_target()?;
+        }
+
+        Ok(squared_error_sum / n)
+    }
+
+    /// Blend every layer of `target_network` toward `decision_network`:
+    /// `theta_target = tau*theta_online + (1-tau)*theta_target`.
+    fn soft_update_target(&mut self) -> PyResult<()> {
+        let online = self.decision_network.as_ref().unwrap().get_layers();
+        let mut target_layers = self.target_network.as_ref().unwrap().get_layers();
+
+        for (target_layer, online_layer) in target_layers.iter_mut().zip(online.iter()) {
+            let blended_weights: Vec<Vec<Float>> = target_layer
+                .get_weights()
+                .iter()
+                .zip(online_layer.get_weights().iter())
+                .map(|(t_row, o_row)| {
+                    t_row
+                        .iter()
+                        .zip(o_row.iter())
+                        .map(|(t, o)| self.tau * o + (1.0 - self.tau) * t)
+                        .collect()
+                })
+                .collect();
+            let blended_biases: Vec<Float> = target_layer
+                .get_biases()
+                .iter()
+                .zip(online_layer.get_biases().iter())
+                .map(|(t, o)| self.tau * o + (1.0 - self.tau) * t)
+                .collect();
+
+            target_layer.update_weights(blended_weights)?;
+            target_layer.update_biases(blended_biases)?;
+        }
+
+        let mut target_network = self.target_network.as_ref().unwrap().clone();
+        target_network.set_layers(target_layers)?;
+        self.target_network = Some(target_network);
+
+        Ok(())
+    }
+}
+
+impl IntelligentAgent {
+    /// Read the raw value a `Consideration` scores, before its curve is applied.
+    ///
+    /// Lives outside the `#[pymethods]` block above because it takes a
+    /// `&HashMap<String, Float>`, which isn't `FromPyObject`-compatible —
+    /// PyO3 macro-processes every fn in a `#[pymethods]` block regardless of
+    /// visibility, so this has to stay in a plain `impl` block like
+    /// `ppo_last_layer_update` below.
+    fn consideration_raw_input(&self, consideration: &Consideration, context: &HashMap<String, Float>) -> Float {
+        match consideration.input_kind {
+            ConsiderationInputKind::CognitiveField => match consideration.key.as_str() {
+                "attention" => self.cognitive_state.attention,
+                "memory_capacity" => self.cognitive_state.memory_capacity,
+                "processing_speed" => self.cognitive_state.processing_speed,
+                "creativity" => self.cognitive_state.creativity,
+                "social_awareness" => self.cognitive_state.social_awareness,
+                "emotional_stability" => self.cognitive_state.emotional_stability,
+                _ => 0.0,
+            },
+            ConsiderationInputKind::Gene => self.dna.genes.get(consideration.index).copied().unwrap_or(0.0),
+            ConsiderationInputKind::Memory => self.memory.get(&consideration.key).copied().unwrap_or(0.0),
+            ConsiderationInputKind::Situation => context.get(&consideration.key).copied().unwrap_or(0.0),
+        }
+    }
+
+    /// Score `action` by multiplying its considerations' curve outputs,
+    /// compensated for the pessimism of multiplying several sub-1 values.
+    fn score_action(&self, action: &Action, context: &HashMap<String, Float>) -> Float {
+        if action.considerations.is_empty() {
+            return 0.0;
+        }
+
+        let product: Float = action
+            .considerations
+            .iter()
+            .map(|c| c.curve.evaluate(self.consideration_raw_input(c, context)))
+            .product();
+
+        compensate(product, action.considerations.len())
+    }
+
+    /// One clipped-surrogate PPO gradient step on the decision network's
+    /// last layer, given a pooled batch of GAE `TrainingSample`s. Follows
+    /// the same last-layer-only simplification as `learn()` (the network
+    /// has no generic backprop yet): treats the last layer's raw outputs as
+    /// policy logits, softmaxes them, and nudges them via the standard
+    /// softmax/cross-entropy gradient `(onehot(action) - probs)`, scaled by
+    /// `advantage` and zeroed once the probability ratio has already moved
+    /// past `clip_epsilon` in the direction the advantage would push it
+    /// further — the same "don't update once clipping binds" behavior as
+    /// the clipped surrogate objective. Returns the batch's mean clipped
+    /// surrogate value (0.0 if `samples` is empty).
+    pub(crate) fn ppo_last_layer_update(
+        &mut self,
+        samples: &[TrainingSample],
+        learning_rate: Float,
+        clip_epsilon: Float,
+    ) -> PyResult<Float> {
+        if samples.is_empty() {
+            return Ok(0.0);
+        }
+
+        let network = self
+            .decision_network
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Decision network not set"))?
+            .clone();
+
+        let mut layers = network.get_layers();
+        let last = layers.len() - 1;
+
+        let mut weight_grad = vec![vec![0.0; layers[last].get_input_size()]; layers[last].get_output_size()];
+        let mut bias_grad = vec![0.0; layers[last].get_output_size()];
+        let mut surrogate_sum = 0.0;
+
+        for sample in samples {
+            let layer_outputs = network.forward_with_layer_outputs(sample.state.clone())?;
+            let logits = &layer_outputs[last];
+            let probs = softmax(logits);
+
+            let action = sample.action.min(probs.len() - 1);
+            let new_log_prob = probs[action].max(1e-8).ln();
+            let ratio = (new_log_prob - sample.old_log_prob).exp();
+            let clipped_ratio = ratio.clamp(1.0 - clip_epsilon, 1.0 + clip_epsilon);
+            surrogate_sum += (ratio * sample.advantage).min(clipped_ratio * sample.advantage);
+
+            let clip_active = (ratio < 1.0 - clip_epsilon && sample.advantage < 0.0)
+                || (ratio > 1.0 + clip_epsilon && sample.advantage > 0.0);
+            let scale = if clip_active { 0.0 } else { ratio * sample.advantage };
+
+            let last_layer_input = if last == 0 {
+                &sample.state
+            } else {
+                &layer_outputs[last - 1]
+            };
+
+            for (k, prob) in probs.iter().enumerate() {
+                let indicator = if k == action { 1.0 } else { 0.0 };
+                let delta = scale * (indicator - prob);
+                for (grad, input) in weight_grad[k].iter_mut().zip(last_layer_input.iter()) {
+                    *grad += delta * input;
+                }
+                bias_grad[k] += delta;
+            }
+        }
+
+        let n = samples.len() as Float;
+        let mut new_weights = layers[last].get_weights();
+        let mut new_biases = layers[last].get_biases();
+        for (w_row, g_row) in new_weights.iter_mut().zip(weight_grad.iter()) {
+            for (w, g) in w_row.iter_mut().zip(g_row.iter()) {
+                *w += learning_rate * g / n;
+            }
+        }
+        for (b, g) in new_biases.iter_mut().zip(bias_grad.iter()) {
+            *b += learning_rate * g / n;
+        }
+        layers[last].update_weights(new_weights)?;
+        layers[last].update_biases(new_biases)?;
+
+        let mut network = network;
+        network.set_layers(layers)?;
+        self.decision_network = Some(network);
+
+        Ok(surrogate_sum / n)
+    }
+}
+
+/// One step recorded into an `AgentBuffer`, as fed to
+/// `AgentSociety::record_step`. `value` and `log_prob` are supplied by the
+/// caller at selection time (the critic's estimate of `V(state)` and the
+/// policy's log-probability of `action`), mirroring how `Transition` carries
+/// pre-computed fields into `IntelligentAgent::observe`.
+#[pyclass]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AgentStep {
+    #[pyo3(get)]
+    pub state: Vec<Float>,
+
+    #[pyo3(get)]
+    pub action: usize,
+
+    #[pyo3(get)]
+    pub reward: Float,
+
+    #[pyo3(get)]
+    pub value: Float,
+
+    #[pyo3(get)]
+    pub log_prob: Float,
+
+    #[pyo3(get)]
+    pub done: bool,
+
+    /// The critic's estimate of `V(next_state)`, supplied by the caller at
+    /// selection time just like `value`. Only consulted by
+    /// `AgentBuffer::close_trajectory` for the last step of a trajectory
+    /// that closes due to `time_horizon` rather than `done` — a real
+    /// bootstrap for a truncated-but-ongoing episode, as opposed to the `0.0`
+    /// used when `done` is true. Ignored otherwise.
+    #[pyo3(get)]
+    pub next_value: Float,
+}
+
+#[pymethods]
+impl AgentStep {
+    #[new]
+    #[pyo3(signature = (state, action, reward, value, log_prob, done, next_value = 0.0))]
+    pub fn new(
+        state: Vec<Float>,
+        action: usize,
+        reward: Float,
+        value: Float,
+        log_prob: Float,
+        done: bool,
+        next_value: Float,
+    ) -> Self {
+        Self { state, action, reward, value, log_prob, done, next_value }
+    }
+}
+
+/// A training sample produced once a trajectory closes out: the original
+/// `state`/`action`, its GAE `advantage` and bootstrapped `return_`, and the
+/// `log_prob` recorded when the action was taken (needed for the PPO
+/// probability ratio in `IntelligentAgent::ppo_last_layer_update`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct TrainingSample {
+    state: Vec<Float>,
+    action: usize,
+    advantage: Float,
+    return_: Float,
+    old_log_prob: Float,
+}
+
+/// Per-agent trajectory collector. Accumulates `AgentStep`s and closes out a
+/// trajectory (computing GAE advantages/returns) once it hits a terminal
+/// step or `time_horizon` steps; see `AgentSociety::record_step`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct AgentBuffer {
+    steps: Vec<AgentStep>,
+}
+
+impl AgentBuffer {
+    fn push(&mut self, step: AgentStep) {
+        self.steps.push(step);
+    }
+
+    /// Compute GAE advantages and returns for every buffered step and drain
+    /// them into `TrainingSample`s, working backward over the trajectory:
+    /// `delta_t = r_t + gamma*V(s_{t+1}) - V(s_t)` (bootstrap value zeroed
+    /// only on terminal steps; for the last buffered step of a
+    /// `time_horizon`-truncated, *non*-terminal trajectory it's the step's
+    /// own caller-supplied `next_value`), `A_t = delta_t +
+    /// gamma*lambda*A_{t+1}` (also zeroed across a terminal boundary), and
+    /// `R_t = A_t + V(s_t)`.
+    fn close_trajectory(&mut self, gamma: Float, lambda: Float) -> Vec<TrainingSample> {
+        let n = self.steps.len();
+        let mut advantages = vec![0.0; n];
+        let mut running_advantage = 0.0;
+
+        for t in (0..n).rev() {
+            let step = &self.steps[t];
+            let next_value = if step.done {
+                0.0
+            } else {
+                self.steps.get(t + 1).map_or(step.next_value, |s| s.value)
+            };
+            let delta = step.reward + gamma * next_value - step.value;
+            let carry = if step.done { 0.0 } else { running_advantage };
+            running_advantage = delta + gamma * lambda * carry;
+            advantages[t] = running_advantage;
+        }
+
+        let samples: Vec<TrainingSample> = self
+            .steps
+            .drain(..)
+            .zip(advantages)
+            .map(|(step, advantage)| TrainingSample {
+                return_: advantage + step.value,
+                state: step.state,
+                action: step.action,
+                advantage,
+                old_log_prob: step.log_prob,
+            })
+            .collect();
+
+        if !samples.is_empty() {
+            let mean_return: Float = samples.iter().map(|s| s.return_).sum::<Float>() / samples.len() as Float;
+            debug!("Closed trajectory of {} steps, mean return {:.3}", samples.len(), mean_return);
+        }
+
+        samples
+    }
+}
+
+/// Build a forward-dynamics model's input: state features followed by the
+/// chosen action encoded as a single scalar.
+fn dynamics_input(state: &[Float], action: usize) -> Vec<Float> {
+    let mut input = state.to_vec();
+    input.push(action as Float);
+    input
+}
+
+/// Mean squared error between predicted and actual next-state features.
+fn squared_prediction_error(predicted: &[Float], actual: &[Float]) -> Float {
+    if predicted.is_empty() {
+        return 0.0;
+    }
+    predicted
+        .iter()
+        .zip(actual.iter())
+        .map(|(p, a)| (p - a).powi(2))
+        .sum::<Float>()
+        / predicted.len() as Float
+}
+
+/// Numerically-stable softmax: subtract the row max before exponentiating.
+fn softmax(logits: &[Float]) -> Vec<Float> {
+    let max = logits.iter().cloned().fold(Float::NEG_INFINITY, Float::max);
+    let exps: Vec<Float> = logits.iter().map(|x| (x - max).exp()).collect();
+    let sum: Float = exps.iter().sum();
+    if sum > 0.0 {
+        exps.iter().map(|e| e / sum).collect()
+    } else {
+        vec![1.0 / logits.len() as Float; logits.len()]
+    }
+}
+
+/// Index of the largest value in `values` (first occurrence on ties).
+fn argmax(values: &[Float]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+/// Write `value` to `path` as JSON or `bincode`, per the `format` argument
+/// accepted by `IntelligentAgent::save` / `AgentSociety::save`.
+fn write_checkpoint<T: Serialize>(value: &T, path: &str, format: &str) -> PyResult<()> {
+    match format.to_lowercase().as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(value).map_err(LoreError::from)?;
+            std::fs::write(path, json).map_err(LoreError::from)?;
+        }
+        "binary" => {
+            let bytes = bincode::serialize(value).map_err(LoreError::from)?;
+            std::fs::write(path, bytes).map_err(LoreError::from)?;
+        }
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown format: {} (expected \"json\" or \"binary\")",
+                format
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Read a value previously written by `write_checkpoint`.
+fn read_checkpoint<T: for<'de> Deserialize<'de>>(path: &str, format: &str) -> PyResult<T> {
+    match format.to_lowercase().as_str() {
+        "json" => {
+            let contents = std::fs::read_to_string(path).map_err(LoreError::from)?;
+            Ok(serde_json::from_str(&contents).map_err(LoreError::from)?)
+        }
+        "binary" => {
+            let bytes = std::fs::read(path).map_err(LoreError::from)?;
+            Ok(bincode::deserialize(&bytes).map_err(LoreError::from)?)
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown format: {} (expected \"json\" or \"binary\")",
+            format
+        ))),
+    }
+}
+
+/// Agent society for managing multiple agents
+#[pyclass]
+#[derive(Serialize, Deserialize)]
+pub struct AgentSociety {
+    agents: Vec<IntelligentAgent>,
+    interaction_history: Vec<(String, String, Float)>, // (agent1, agent2, strength)
+
+    /// Per-agent PPO trajectory buffers, keyed by agent id.
+    buffers: HashMap<String, AgentBuffer>,
+    /// Per-agent pooled GAE samples awaiting `train()`, keyed by agent id.
+    training_samples: HashMap<String, Vec<TrainingSample>>,
+    /// GAE discount factor.
+    gamma: Float,
+    /// GAE lambda (bias/variance trade-off for the advantage estimate).
+    lambda: Float,
+    /// Trajectory closes out after this many steps even without a terminal one.
+    time_horizon: usize,
+}
+
+#[pymethods]
+impl AgentSociety {
+    #[new]
+    #[pyo3(signature = (gamma = 0.99, lambda = 0.95, time_horizon = 128))]
+    pub fn new(gamma: Float, lambda: Float, time_horizon: usize) -> Self {
+        info!("Creating new agent society");
+
+        Self {
+            agents: Vec::new(),
+            interaction_history: Vec::new(),
+            buffers: HashMap::new(),
+            training_samples: HashMap::new(),
+            gamma,
+            lambda,
+            time_horizon: time_horizon.max(1),
+        }
+    }
+
+    /// Add agent to society
+    pub fn add_agent(&mut self, agent: IntelligentAgent) {
+        info!("Adding agent {} to society", agent.id);
+        self.agents.push(agent);
+    }
+
+    /// Record one step of experience for `agent_id`'s PPO trajectory buffer,
+    /// closing the trajectory out (computing GAE advantages/returns into the
+    /// pooled samples `train()` draws from) once it hits a terminal step or
+    /// `time_horizon` steps.
+    pub fn record_step(&mut self, agent_id: String, step: AgentStep) {
+        let done = step.done;
+        let buffer = self.buffers.entry(agent_id.clone()).or_default();
+        buffer.push(step);
+
+        if done || buffer.steps.len() >= self.time_horizon {
+            let samples = buffer.close_trajectory(self.gamma, self.lambda);
+            self.training_samples.entry(agent_id).or_default().extend(samples);
+        }
+    }
+
+    /// Number of GAE samples pooled for `agent_id` and awaiting `train()`.
+    pub fn pending_samples(&self, agent_id: String) -> usize {
+        self.training_samples.get(&agent_id).map_or(0, Vec::len)
+    }
+
+    /// Train every agent with pending PPO samples: for `num_epochs` passes,
+    /// shuffle each agent's pooled samples and apply a clipped-surrogate
+    /// last-layer gradient step (`IntelligentAgent::ppo_last_layer_update`)
+    /// per `batch_size`-sized minibatch, using that agent's own
+    /// `learning_rate`. Clears the pooled samples once done. Returns the
+    /// mean clipped surrogate value across all updates, or 0.0 if nothing
+    /// was pending.
+    pub fn train(&mut self, num_epochs: usize, batch_size: usize, clip_epsilon: Float) -> PyResult<Float> {
+        let mut rng = thread_rng();
+        let mut total_surrogate = 0.0;
+        let mut update_count = 0u64;
+        let batch_size = batch_size.max(1);
+
+        let Self { agents, training_samples, .. } = self;
+
+        for _ in 0..num_epochs.max(1) {
+            for agent in agents.iter_mut() {
+                let samples = match training_samples.get_mut(&agent.id) {
+                    Some(s) if !s.is_empty() => s,
+                    _ => continue,
+                };
+                samples.shuffle(&mut rng);
+
+                for batch in samples.chunks(batch_size) {
+                    let surrogate = agent.ppo_last_layer_update(batch, agent.learning_rate, clip_epsilon)?;
+                    total_surrogate += surrogate;
+                    update_count += 1;
+                }
+            }
+        }
+
+        for samples in training_samples.values_mut() {
+            samples.clear();
+        }
+
+        Ok(if update_count > 0 {
+            total_surrogate / update_count as Float
+        } else {
+            0.0
+        })
+    }
+
+    /// Save every agent and the interaction history to `path`, so a whole
+    /// simulation can be checkpointed and resumed later. `format` is
+    /// `"json"` or `"binary"`, as in `IntelligentAgent::save`.
+    pub fn save(&self, path: String, format: String) -> PyResult<()> {
+        write_checkpoint(self, &path, &format)
+    }
+
+    /// Load a society previously written by `save`.
+    #[staticmethod]
+    pub fn load(path: String, format: String) -> PyResult<Self> {
+        read_checkpoint(&path, &format)
+    }
+    
+    /// Get society size
+    pub fn get_size(&self) -> usize {
+        self.agents.len()
+    }
+    
+    /// Simulate social interactions
+    pub fn simulate_interactions(&mut self, num_interactions: usize) -> PyResult<usize> {
+        if self.agents.len() < 2 {
+            return Ok(0);
+        }
+        
+        let mut rng = thread_rng();
+        let mut interactions_created = 0;
+        
+        for _ in 0..num_interactions {
+            // Select two random agents
+            let agent1_idx = rng.gen_range(0..self.agents.len());
+            let mut agent2_idx = rng.gen_range(0..self.agents.len());
+            
+            while agent2_idx == agent1_idx {
+                agent2_idx = rng.gen_range(0..self.agents.len());
+            }
+            
+            let agent1_id = self.agents[agent1_idx].id.clone();
+            let agent2_id = self.agents[agent2_idx].id.clone();
+            
+            // Calculate interaction strength based on compatibility
+            let agent1_social = self.agents[agent1_idx].cognitive_state.social_awareness;
+            let agent2_social = self.agents[agent2_idx].cognitive_state.social_awareness;
+            
+            let interaction_strength = (agent1_social + agent2_social) / 2.0 * rng.gen::<Float>();
+            
+            // Create mutual connections if strong enough
+            if interaction_strength > 0.5 {
+                self.agents[agent1_idx].add_social_connection(agent2_id.clone());
+                self.agents[agent2_idx].add_social_connection(agent1_id.clone());
+                
+                // Both agents gain social experience
+                let _ = self.agents[agent1_idx].gain_experience(10, "social".to_string());
+                let _ = self.agents[agent2_idx].gain_experience(10, "social".to_string());
+                
+                interactions_created += 1;
+            }
+            
+            // Record interaction
+            self.interaction_history.push((agent1_id, agent2_id, interaction_strength));
+        }
+        
+        info!("Simulated {} interactions, {} connections created", num_interactions, interactions_created);
+        Ok(interactions_created)
+    }
+    
+    /// Get society statistics
+    pub fn get_society_stats(&self) -> HashMap<String, Float> {
+        let mut stats = HashMap::new();
+        
+        if self.agents.is_empty() {
+            return stats;
+        }
+        
+        let total_connections: usize = self.agents.iter()
+            .map(|agent| agent.get_social_network_size())
+            .sum();
+        
+        let avg_connections = total_connections as Float / self.agents.len() as Float;
+        
+        let total_experience: u64 = self.agents.iter()
+            .map(|agent| agent.experience_points)
+            .sum();
+        
+        let avg_experience = total_experience as Float / self.agents.len() as Float;
+        
+        let avg_cognitive_capacity: Float = self.agents.iter()
+            .map(|agent| agent.cognitive_state.get_capacity())
+            .sum::<Float>() / self.agents.len() as Float;
+        
+        stats.insert("total_agents".to_string(), self.agents.len() as Float);
+        stats.insert("total_connections".to_string(), total_connections as Float);
+        stats.insert("avg_connections".to_string(), avg_connections);
+        stats.insert("avg_experience".to_string(), avg_experience);
+        stats.insert("avg_cognitive_capacity".to_string(), avg_cognitive_capacity);
+        stats.insert("total_interactions".to_string(), self.interaction_history.len() as Float);
+        
+        stats
+    }
+    
+    /// Run collective decision making
+    pub fn collective_decision(&mut self, situation: Vec<Float>) -> PyResult<Vec<Float>> {
+        if self.agents.is_empty() {
+            return Ok(vec![0.0; 3]);
+        }
+
+        let timer = Instant::now();
+
+        // Collect decisions from all agents
+        let decisions: Result<Vec<Vec<Float>>, _> = self.agents
+            .iter_mut()
+            .map(|agent| agent.make_decision(situation.clone()))
+            .collect();
+        
+        let all_decisions = decisions?;
+        
+        // Average the decisions (simple consensus)
+        let decision_size = all_decisions[0].len();
+        let mut collective_decision = vec![0.0; decision_size];
+        
+        for decision in &all_decisions {
+            for (i, &value) in decision.iter().enumerate() {
+                collective_decision[i] += value;
+            }
+        }
+        
+        for value in &mut collective_decision {
+            *value /= self.agents.len() as Float;
+        }
+        
+        let elapsed = timer.elapsed().as_millis();
+        info!("Collective decision made by {} agents in {}ms", self.agents.len(), elapsed);
+        
+        Ok(collective_decision)
+    }
+}
+
+/// Generate random cognitive state
+#[pyfunction]
+pub fn generate_random_cognitive_state() -> PyResult<CognitiveState> {
+    let mut rng = thread_rng();
+    
+    CognitiveState::new(
+        rng.gen_range(0.3..0.9),  // attention
+        rng.gen_range(0.4..0.9),  // memory_capacity
+        rng.gen_range(0.3..0.8),  // processing_speed
+        rng.gen_range(0.2..0.9),  // creativity
+        rng.gen_range(0.3..0.8),  // social_awareness
+        rng.gen_range(0.4..0.9),  // emotional_stability
+    )
+}
+
+/// Create agent with neural decision network
+#[pyfunction]
+pub fn create_agent_with_neural_brain(
+    id: String,
+    dna: AgentDNA,
+    behavior: String,
+    brain_architecture: Vec<usize>,
+) -> PyResult<IntelligentAgent> {
+    use crate::neural::{ActivationType, NeuralNetwork};
+    
+    // Create cognitive state based on DNA
+    let cognitive_state = CognitiveState::new(
+        dna.genes.get(0).copied().unwrap_or(0.5).abs().min(1.0),
+        dna.genes.get(1).copied().unwrap_or(0.5).abs().min(1.0),
+        dna.genes.get(2).copied().unwrap_or(0.5).abs().min(1.0),
+        dna.genes.get(3).copied().unwrap_or(0.5).abs().min(1.0),
+        dna.genes.get(4).copied().unwrap_or(0.5).abs().min(1.0),
+        dna.genes.get(5).copied().unwrap_or(0.5).abs().min(1.0),
+    )?;
+    
+    let behavior_type = BehaviorType::new(behavior)?;
+    
+    // Create agent
+    let mut agent = IntelligentAgent::new(
+        id,
+        dna,
+        behavior_type,
+        cognitive_state,
+        10000,
+        0.99,
+        0.01,
+        1,
+        0.01,
+        1.0,
+        0.995,
+        0.01,
+        0.0,
+    )?;
+    
+    // Create neural decision network
+    if brain_architecture.len() >= 2 {
+        let activations = vec![ActivationType::ReLU; brain_architecture.len() - 1];
+        let network = NeuralNetwork::new(brain_architecture, activations)?;
+        agent.set_decision_network(network);
+    }
+    
+    Ok(agent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(reward: Float, value: Float, done: bool, next_value: Float) -> AgentStep {
+        AgentStep::new(vec![0.0], 0, reward, value, 0.0, done, next_value)
+    }
+
+    /// Two-step terminal trajectory, gamma=lambda=1.0 for exact arithmetic:
+    /// `delta_1 = r_1 - v_1 = 1.0`, `A_1 = 1.0`;
+    /// `delta_0 = r_0 + v_1 - v_0 = 1.5`, `A_0 = delta_0 + A_1 = 2.5`.
+    #[test]
+    fn close_trajectory_computes_gae_for_a_terminal_episode() {
+        let mut buffer = AgentBuffer::default();
+        buffer.push(step(1.0, 0.5, false, 0.0));
+        buffer.push(step(2.0, 1.0, true, 0.0));
+
+        let samples = buffer.close_trajectory(1.0, 1.0);
+
+        assert_eq!(samples.len(), 2);
+        assert!((samples[0].advantage - 2.5).abs() < 1e-9);
+        assert!((samples[0].return_ - 3.0).abs() < 1e-9);
+        assert!((samples[1].advantage - 1.0).abs() < 1e-9);
+        assert!((samples[1].return_ - 2.0).abs() < 1e-9);
+    }
+
+    /// A single non-terminal step closed out by `time_horizon` truncation
+    /// must bootstrap off its own `next_value`, not zero: with gamma=lambda=1
+    /// and `next_value=5.0`, `delta_0 = r_0 + next_value - v_0 = 5.5`.
+    #[test]
+    fn close_trajectory_bootstraps_truncated_steps_from_next_value() {
+        let mut buffer = AgentBuffer::default();
+        buffer.push(step(1.0, 0.5, false, 5.0));
+
+        let samples = buffer.close_trajectory(1.0, 1.0);
+
+        assert_eq!(samples.len(), 1);
+        assert!((samples[0].advantage - 5.5).abs() < 1e-9);
+        assert!((samples[0].return_ - 6.0).abs() < 1e-9);
+    }
+}
+
+/// Register agent system functions with Python
+pub fn register_agent_functions(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<BehaviorType>()?;
+    m.add_class::<CognitiveState>()?;
+    m.add_class::<Transition>()?;
+    m.add_class::<AgentStep>()?;
+    m.add_class::<ResponseCurveKind>()?;
+    m.add_class::<ResponseCurve>()?;
+    m.add_class::<ConsiderationInputKind>()?;
+    m.add_class::<Consideration>()?;
+    m.add_class::<Action>()?;
+    m.add_class::<IntelligentAgent>()?;
+    m.add_class::<AgentSociety>()?;
+    m.add_function(wrap_pyfunction!(generate_random_cognitive_state, m)?)?;
+    m.add_function(wrap_pyfunction!(create_agent_with_neural_brain, m)?)?;
+    
+    info!("Agent system functions registered successfully");
+    Ok(())
+}
@@ -11,16 +11,43 @@ use pyo3::exceptions::PyValueError;
 use rayon::prelude::*;
 use rand::prelude::*;
 use rand_distr::Normal;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::io::Write as _;
+use std::sync::Mutex;
+use std::sync::RwLock;
 use std::time::Instant;
 use tracing::info;
 
+pub mod selection;
+pub mod spea2;
+
+/// Stop criteria for a `GeneticEngine::evolve` run, checked once per
+/// generation in the order they are configured. The first criterion that
+/// triggers ends the run.
+#[derive(Debug, Clone)]
+pub enum StopCriteria {
+    /// Stop after this many generations have elapsed.
+    MaxGenerations(u64),
+    /// Stop as soon as the best fitness reaches (or exceeds) this value.
+    TargetFitness(Float),
+    /// Stop when the best fitness improves by less than `epsilon` over
+    /// `generations` consecutive generations.
+    FitnessStagnation { generations: u64, epsilon: Float },
+    /// Stop once this many fitness evaluations have been performed.
+    MaxEvaluations(u64),
+}
+
 /// High-performance genetic evolution engine
 #[pyclass]
 pub struct GeneticEngine {
     params: EvolutionParams,
     generation_counter: AtomicU64,
     evaluation_counter: AtomicU64,
+    fitness_cache: RwLock<HashMap<Vec<OrderedFloat64>, Float>>,
+    cache_hits: AtomicU64,
+    cache_lookups: AtomicU64,
+    progress_log: Mutex<Option<std::path::PathBuf>>,
 }
 
 #[pymethods]
@@ -28,25 +55,47 @@ impl GeneticEngine {
     #[new]
     pub fn new(params: EvolutionParams) -> PyResult<Self> {
         params.validate()?;
-        
+
         info!("GeneticEngine initialized with population size: {}", params.population_size);
-        
+
         Ok(Self {
             params,
             generation_counter: AtomicU64::new(0),
             evaluation_counter: AtomicU64::new(0),
+            fitness_cache: RwLock::new(HashMap::new()),
+            cache_hits: AtomicU64::new(0),
+            cache_lookups: AtomicU64::new(0),
+            progress_log: Mutex::new(None),
         })
     }
-    
+
+    /// Stream one tab-separated row per generation (with a header) to `path`
+    /// as `evolve()` runs, so long runs can be tailed/plotted live instead of
+    /// only inspected via the final `EvolutionResult`.
+    pub fn set_progress_log(&self, path: String) {
+        *self.progress_log.lock().unwrap() = Some(std::path::PathBuf::from(path));
+    }
+
     /// Get current generation
     pub fn get_generation(&self) -> u64 {
         self.generation_counter.load(Ordering::Relaxed)
     }
-    
+
     /// Get total evaluations performed
     pub fn get_evaluations(&self) -> u64 {
         self.evaluation_counter.load(Ordering::Relaxed)
     }
+
+    /// Fraction of fitness lookups served from `fitness_cache` instead of the
+    /// Python fitness callback. Only meaningful when
+    /// `EvolutionParams.enable_fitness_cache` is set; returns 0.0 otherwise.
+    pub fn cache_hit_rate(&self) -> Float {
+        let lookups = self.cache_lookups.load(Ordering::Relaxed);
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.cache_hits.load(Ordering::Relaxed) as Float / lookups as Float
+    }
     
     /// Get population size
     pub fn get_population_size(&self) -> usize {
@@ -95,9 +144,383 @@ impl GeneticEngine {
                 agent.mutations += 1;
             }
         }
-        
+
         Ok(())
     }
+
+    /// Run a full generational loop: evaluate fitness, apply elitism, select
+    /// parents, cross over and mutate, and repeat until a configured stop
+    /// criterion triggers.
+    ///
+    /// `fitness_fn` is a Python callable `fitness_fn(genes: List[float]) -> float`
+    /// invoked once per un-evaluated individual each generation.
+    pub fn evolve(
+        &self,
+        py: Python<'_>,
+        mut population: Vec<AgentDNA>,
+        fitness_fn: PyObject,
+    ) -> PyResult<EvolutionResult> {
+        if population.is_empty() {
+            return Err(PyValueError::new_err("Population must not be empty"));
+        }
+
+        let timer = Instant::now();
+        let criteria = self.build_stop_criteria();
+        let mut best_history: Vec<Float> = Vec::new();
+        let mut history: Vec<GenerationStats> = Vec::new();
+        let mut best_agent: Option<AgentDNA> = None;
+        let mut generation: u64 = 0;
+        let mut success = false;
+
+        loop {
+            // Evaluating fitness calls back into the Python `fitness_fn`, so this
+            // stays sequential under the GIL rather than running on the Rayon
+            // pool (the same restriction `ensemble_predict` works around above).
+            for agent in population.iter_mut() {
+                if agent.has_fitness() {
+                    continue;
+                }
+
+                let cache_key = self
+                    .params
+                    .enable_fitness_cache
+                    .then(|| quantize_genes(&agent.genes, self.params.cache_precision));
+
+                let cached = cache_key.as_ref().and_then(|key| {
+                    self.cache_lookups.fetch_add(1, Ordering::Relaxed);
+                    self.fitness_cache.read().unwrap().get(key).copied()
+                });
+
+                if let Some(score) = cached {
+                    self.cache_hits.fetch_add(1, Ordering::Relaxed);
+                    agent.set_fitness(score);
+                    continue;
+                }
+
+                let score: Float = fitness_fn.call1(py, (agent.genes.clone(),))?.extract(py)?;
+                agent.set_fitness(score);
+                self.evaluation_counter.fetch_add(1, Ordering::Relaxed);
+
+                if let Some(key) = cache_key {
+                    self.fitness_cache.write().unwrap().insert(key, score);
+                }
+            }
+
+            let minimize = self.params.minimize;
+            population.sort_by(|a, b| signed_fitness(a.get_fitness(), minimize).partial_cmp(&signed_fitness(b.get_fitness(), minimize)).unwrap());
+
+            let fitnesses: Vec<Float> = population.iter().map(AgentDNA::get_fitness).collect();
+            let best_fitness = fitnesses[0];
+            let average_fitness = fitnesses.iter().sum::<Float>() / fitnesses.len() as Float;
+            let fitness_std = (fitnesses.iter().map(|f| (f - average_fitness).powi(2)).sum::<Float>()
+                / fitnesses.len() as Float)
+                .sqrt();
+            let diversity_index = Self::population_diversity(&population);
+
+            best_history.push(best_fitness);
+
+            if best_agent.as_ref().map_or(true, |b| is_better(best_fitness, b.get_fitness(), minimize)) {
+                best_agent = Some(population[0].clone());
+            }
+
+            generation += 1;
+            self.generation_counter.fetch_add(1, Ordering::Relaxed);
+
+            let evaluations = self.evaluation_counter.load(Ordering::Relaxed);
+
+            let progress = generation as Float / self.params.max_generations.max(1) as Float;
+            let mutation_rate = self.params.mutation_schedule.as_ref().map_or(
+                self.params.mutation_rate,
+                |schedule| schedule.evaluate(generation, progress, evaluations),
+            );
+
+            let elapsed_so_far_ms = timer.elapsed().as_millis() as u64;
+            history.push(GenerationStats {
+                generation,
+                best_fitness,
+                average_fitness,
+                fitness_std,
+                diversity_index,
+                evaluations,
+                elapsed_ms: elapsed_so_far_ms,
+                mutation_rate,
+            });
+            self.write_progress_log(history.last().unwrap());
+
+            let stop = self.check_stop_criteria(&criteria, generation, best_fitness, &best_history, evaluations);
+
+            if let Some(run_succeeded) = stop {
+                success = run_succeeded;
+                let elapsed_ms = timer.elapsed().as_millis() as u64;
+                let convergence_rate = if generation > 0 {
+                    (best_fitness - best_history[0]) / generation as Float
+                } else {
+                    0.0
+                };
+
+                let best_agent_py = match best_agent {
+                    Some(agent) => Some(Py::new(py, agent)?),
+                    None => None,
+                };
+                let population_py = population
+                    .into_iter()
+                    .map(|agent| Py::new(py, agent))
+                    .collect::<PyResult<Vec<_>>>()?;
+
+                info!(
+                    "evolve() finished at generation {} (success={}, best_fitness={:.6})",
+                    generation, success, best_fitness
+                );
+
+                return Ok(EvolutionResult {
+                    generation,
+                    best_fitness,
+                    average_fitness,
+                    fitness_std,
+                    convergence_rate,
+                    elapsed_ms,
+                    evaluations,
+                    best_agent: best_agent_py,
+                    population: population_py,
+                    diversity_index,
+                    success,
+                    history,
+                });
+            }
+
+            // Elitism: the best `elitism_count` individuals survive untouched.
+            let elites: Vec<AgentDNA> = population.iter().take(self.params.elitism_count).cloned().collect();
+            let remaining = self.params.population_size - elites.len();
+
+            // Selection uses shared fitness when niching is enabled, which
+            // spreads the population across multiple optima; reported fitness
+            // above always stays the true, unshared value.
+            let selection_fitness = self.params.sharing_radius.map_or_else(
+                || fitnesses.clone(),
+                |radius| shared_fitness(&population, radius, self.params.sharing_alpha),
+            );
+
+            let default_strategy;
+            let strategy = match &self.params.selection_strategy {
+                Some(strategy) => strategy,
+                None => {
+                    default_strategy = SelectionStrategy::tournament(self.params.tournament_size);
+                    &default_strategy
+                }
+            };
+            let parents1 = selection::select_parents(&population, &selection_fitness, strategy, remaining, minimize);
+            let parents2 = selection::select_parents(&population, &selection_fitness, strategy, remaining, minimize);
+
+            let offspring = parallel_crossover(parents1, parents2, self.params.crossover_rate)?;
+            let mut offspring = parallel_mutation(offspring, mutation_rate, 0.1)?;
+            for child in &mut offspring {
+                child.generation = generation;
+            }
+
+            population = elites;
+            population.extend(offspring);
+        }
+    }
+}
+
+impl GeneticEngine {
+    /// Build the list of stop criteria implied by `self.params`.
+    fn build_stop_criteria(&self) -> Vec<StopCriteria> {
+        let mut criteria = vec![StopCriteria::MaxGenerations(self.params.max_generations)];
+
+        if let Some(target) = self.params.target_fitness {
+            criteria.push(StopCriteria::TargetFitness(target));
+        }
+        if let Some(generations) = self.params.stagnation_generations {
+            criteria.push(StopCriteria::FitnessStagnation {
+                generations,
+                epsilon: self.params.stagnation_epsilon,
+            });
+        }
+        if let Some(max_evaluations) = self.params.max_evaluations {
+            criteria.push(StopCriteria::MaxEvaluations(max_evaluations));
+        }
+
+        criteria
+    }
+
+    /// Append one tab-separated row for `stats` to the configured progress
+    /// log, writing a header first if the file doesn't exist yet. Silently
+    /// does nothing if no log path is configured; logs (rather than
+    /// propagates) IO errors since a failing progress log shouldn't abort
+    /// an otherwise-successful evolve() run.
+    fn write_progress_log(&self, stats: &GenerationStats) {
+        let guard = self.progress_log.lock().unwrap();
+        let path = match guard.as_ref() {
+            Some(path) => path,
+            None => return,
+        };
+
+        let write_header = !path.exists();
+        let file = std::fs::OpenOptions::new().create(true).append(true).open(path);
+        let mut file = match file {
+            Ok(file) => file,
+            Err(e) => {
+                tracing::warn!("Failed to open progress log {:?}: {}", path, e);
+                return;
+            }
+        };
+
+        if write_header {
+            let _ = writeln!(
+                file,
+                "generation\tbest_fitness\taverage_fitness\tfitness_std\tdiversity_index\tevaluations\telapsed_ms\tmutation_rate"
+            );
+        }
+
+        let _ = writeln!(
+            file,
+            "{}\t{:.6}\t{:.6}\t{:.6}\t{:.6}\t{}\t{}\t{:.6}",
+            stats.generation,
+            stats.best_fitness,
+            stats.average_fitness,
+            stats.fitness_std,
+            stats.diversity_index,
+            stats.evaluations,
+            stats.elapsed_ms,
+            stats.mutation_rate,
+        );
+    }
+
+    /// Check all configured stop criteria for the generation just evaluated.
+    /// Returns `None` to keep going, or `Some(success)` to stop, where
+    /// `success` reports whether the configured target fitness (if any) was
+    /// reached.
+    fn check_stop_criteria(
+        &self,
+        criteria: &[StopCriteria],
+        generation: u64,
+        best_fitness: Float,
+        best_history: &[Float],
+        evaluations: u64,
+    ) -> Option<bool> {
+        let minimize = self.params.minimize;
+        let reached_target = |fitness: Float| {
+            self.params
+                .target_fitness
+                .map_or(true, |t| !is_better(t, fitness, minimize))
+        };
+
+        for criterion in criteria {
+            match criterion {
+                StopCriteria::MaxGenerations(max) => {
+                    if generation >= *max {
+                        return Some(reached_target(best_fitness));
+                    }
+                }
+                StopCriteria::TargetFitness(target) => {
+                    if !is_better(*target, best_fitness, minimize) {
+                        return Some(true);
+                    }
+                }
+                StopCriteria::FitnessStagnation { generations, epsilon } => {
+                    let window = *generations as usize;
+                    if best_history.len() > window {
+                        let past = best_history[best_history.len() - window - 1];
+                        let current = *best_history.last().unwrap();
+                        if (current - past).abs() < *epsilon {
+                            return Some(reached_target(best_fitness));
+                        }
+                    }
+                }
+                StopCriteria::MaxEvaluations(max) => {
+                    if evaluations >= *max {
+                        return Some(reached_target(best_fitness));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Population diversity as the root-mean-square distance of each
+    /// individual's genes from the population centroid.
+    fn population_diversity(population: &[AgentDNA]) -> Float {
+        if population.len() < 2 || population[0].genes.is_empty() {
+            return 0.0;
+        }
+
+        let gene_count = population[0].genes.len();
+        let mut centroid = vec![0.0; gene_count];
+        for agent in population {
+            for (c, g) in centroid.iter_mut().zip(agent.genes.iter()) {
+                *c += g;
+            }
+        }
+        for c in &mut centroid {
+            *c /= population.len() as Float;
+        }
+
+        let mean_sq_distance: Float = population
+            .iter()
+            .map(|agent| {
+                agent
+                    .genes
+                    .iter()
+                    .zip(centroid.iter())
+                    .map(|(g, c)| (g - c).powi(2))
+                    .sum::<Float>()
+            })
+            .sum::<Float>()
+            / population.len() as Float;
+
+        mean_sq_distance.sqrt()
+    }
+}
+
+/// Fitness-sharing niche count for every individual: `m(i) = sum_j sh(d(i,j))`
+/// with `sh(d) = 1 - (d/sharing_radius)^sharing_alpha` for `d < sharing_radius`
+/// and `0` otherwise. Returns the shared fitness `f(i) / m(i)` used for
+/// selection, keeping the population spread across multiple optima.
+fn shared_fitness(population: &[AgentDNA], sharing_radius: Float, sharing_alpha: Float) -> Vec<Float> {
+    let n = population.len();
+    (0..n)
+        .map(|i| {
+            let niche_count: Float = (0..n)
+                .map(|j| {
+                    let d = gene_distance(&population[i].genes, &population[j].genes);
+                    if d < sharing_radius {
+                        1.0 - (d / sharing_radius).powf(sharing_alpha)
+                    } else {
+                        0.0
+                    }
+                })
+                .sum();
+            population[i].get_fitness() / niche_count.max(1.0)
+        })
+        .collect()
+}
+
+/// Euclidean distance between two gene vectors.
+fn gene_distance(a: &[Float], b: &[Float]) -> Float {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum::<Float>().sqrt()
+}
+
+/// Fitness value to sort ascending-by-"goodness" on, regardless of whether
+/// the run is maximizing or minimizing: the best individual always sorts first.
+fn signed_fitness(fitness: Float, minimize: bool) -> Float {
+    if minimize { fitness } else { -fitness }
+}
+
+/// `true` if `a` is strictly better than `b`, respecting `minimize`.
+fn is_better(a: Float, b: Float, minimize: bool) -> bool {
+    if minimize { a < b } else { a > b }
+}
+
+/// Round each gene to `precision` decimals and wrap in `OrderedFloat` so the
+/// vector can be used as a `HashMap` key for the fitness cache.
+fn quantize_genes(genes: &[Float], precision: u32) -> Vec<OrderedFloat64> {
+    let factor = 10f64.powi(precision as i32);
+    genes
+        .iter()
+        .map(|gene| OrderedFloat64::from((gene * factor).round() / factor))
+        .collect()
 }
 
 /// Parallel crossover function for batch operations
@@ -162,9 +585,10 @@ pub fn parallel_mutation(
 /// Register genetic algorithm functions with Python
 pub fn register_genetic_functions(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<GeneticEngine>()?;
+    m.add_class::<spea2::Spea2Engine>()?;
     m.add_function(wrap_pyfunction!(parallel_crossover, m)?)?;
     m.add_function(wrap_pyfunction!(parallel_mutation, m)?)?;
-    
+
     info!("Genetic algorithm functions registered successfully");
     Ok(())
 }
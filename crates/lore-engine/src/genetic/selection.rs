@@ -0,0 +1,142 @@
+//! # Parent Selection Strategies
+//!
+//! Rayon-parallel implementations of the strategies described by
+//! [`SelectionStrategy`]: tournament, roulette wheel, rank-based, and
+//! stochastic universal sampling (SUS).
+
+use crate::types::*;
+use rand::prelude::*;
+use rayon::prelude::*;
+
+/// Select `count` parents from `population` according to `strategy`.
+/// `fitness` is parallel to `population`; `minimize` inverts "better" so the
+/// same strategies work for cost functions.
+pub fn select_parents(
+    population: &[AgentDNA],
+    fitness: &[Float],
+    strategy: &SelectionStrategy,
+    count: usize,
+    minimize: bool,
+) -> Vec<AgentDNA> {
+    match strategy.kind {
+        SelectionStrategyKind::Tournament => (0..count)
+            .into_par_iter()
+            .map(|_| tournament_pick(population, fitness, strategy.tournament_size, minimize, &mut thread_rng()))
+            .collect(),
+        SelectionStrategyKind::RouletteWheel => {
+            let weights = selection_weights(fitness, minimize);
+            let cumulative = cumulative_sum(&weights);
+            (0..count)
+                .into_par_iter()
+                .map(|_| {
+                    let pick = thread_rng().gen::<Float>() * cumulative.last().copied().unwrap_or(1.0);
+                    population[cumulative_index(&cumulative, pick)].clone()
+                })
+                .collect()
+        }
+        SelectionStrategyKind::RankBased => {
+            let weights = rank_weights(fitness, minimize, strategy.pressure);
+            let cumulative = cumulative_sum(&weights);
+            (0..count)
+                .into_par_iter()
+                .map(|_| {
+                    let pick = thread_rng().gen::<Float>() * cumulative.last().copied().unwrap_or(1.0);
+                    population[cumulative_index(&cumulative, pick)].clone()
+                })
+                .collect()
+        }
+        SelectionStrategyKind::StochasticUniversalSampling => {
+            let weights = selection_weights(fitness, minimize);
+            stochastic_universal_sample(population, &weights, count)
+        }
+    }
+}
+
+fn tournament_pick(
+    population: &[AgentDNA],
+    fitness: &[Float],
+    tournament_size: usize,
+    minimize: bool,
+    rng: &mut impl Rng,
+) -> AgentDNA {
+    let tournament_size = tournament_size.max(1);
+    (0..tournament_size)
+        .map(|_| rng.gen_range(0..population.len()))
+        .max_by(|&a, &b| {
+            let signed = |f: Float| if minimize { -f } else { f };
+            signed(fitness[a]).partial_cmp(&signed(fitness[b])).unwrap()
+        })
+        .map(|idx| population[idx].clone())
+        .expect("population must not be empty")
+}
+
+/// Fitness-proportionate selection weights. For minimization, weights are
+/// inverted around the worst value so lower fitness still gets a larger slice.
+fn selection_weights(fitness: &[Float], minimize: bool) -> Vec<Float> {
+    if !minimize {
+        let min = fitness.iter().copied().fold(Float::INFINITY, Float::min);
+        // Shift so the worst individual still has a nonzero (if small) slice.
+        let shift = if min < 0.0 { -min } else { 0.0 };
+        return fitness.iter().map(|f| f + shift + 1e-9).collect();
+    }
+
+    let max = fitness.iter().copied().fold(Float::NEG_INFINITY, Float::max);
+    fitness.iter().map(|f| (max - f) + 1e-9).collect()
+}
+
+/// Linear rank-based weights: the best individual gets weight `1 + pressure`,
+/// the worst gets `1 - pressure`, interpolated linearly by rank.
+fn rank_weights(fitness: &[Float], minimize: bool, pressure: Float) -> Vec<Float> {
+    let n = fitness.len();
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| {
+        let (fa, fb) = (fitness[a], fitness[b]);
+        if minimize { fa.partial_cmp(&fb).unwrap() } else { fb.partial_cmp(&fa).unwrap() }
+    });
+
+    let pressure = pressure.clamp(0.0, 1.0);
+    let mut weights = vec![0.0; n];
+    for (rank, &idx) in order.iter().enumerate() {
+        let t = rank as Float / (n - 1) as Float; // 0.0 = best, 1.0 = worst
+        weights[idx] = (1.0 + pressure) - 2.0 * pressure * t;
+    }
+    weights
+}
+
+fn cumulative_sum(weights: &[Float]) -> Vec<Float> {
+    let mut total = 0.0;
+    weights
+        .iter()
+        .map(|w| {
+            total += w;
+            total
+        })
+        .collect()
+}
+
+fn cumulative_index(cumulative: &[Float], pick: Float) -> usize {
+    cumulative
+        .iter()
+        .position(|&c| pick <= c)
+        .unwrap_or(cumulative.len() - 1)
+}
+
+/// Stochastic universal sampling: a single sweep over the cumulative weights
+/// with `count` evenly spaced pointers, offset by one shared random start.
+fn stochastic_universal_sample(population: &[AgentDNA], weights: &[Float], count: usize) -> Vec<AgentDNA> {
+    let cumulative = cumulative_sum(weights);
+    let total = cumulative.last().copied().unwrap_or(1.0);
+    let step = total / count.max(1) as Float;
+    let start = thread_rng().gen::<Float>() * step;
+
+    (0..count)
+        .map(|i| {
+            let pointer = start + i as Float * step;
+            population[cumulative_index(&cumulative, pointer)].clone()
+        })
+        .collect()
+}
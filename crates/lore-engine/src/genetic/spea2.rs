@@ -0,0 +1,285 @@
+//! # SPEA2 Multi-Objective Optimization
+//!
+//! Implements the Strength Pareto Evolutionary Algorithm 2 (SPEA2): a
+//! population `P` and an external archive `A` co-evolve each generation.
+//! Environmental selection keeps the archive filled with (approximately)
+//! the non-dominated front, using a k-th nearest neighbor density estimate
+//! to penalize clustering and truncate overflow.
+
+use crate::genetic::{parallel_crossover, parallel_mutation};
+use crate::types::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rand::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::info;
+
+/// SPEA2 multi-objective evolutionary engine producing a Pareto archive.
+#[pyclass]
+pub struct Spea2Engine {
+    population_size: usize,
+    archive_size: usize,
+    minimize: bool,
+    generation_counter: AtomicU64,
+    archive: Vec<AgentDNA>,
+    archive_fitness: Vec<Float>,
+}
+
+#[pymethods]
+impl Spea2Engine {
+    #[new]
+    #[pyo3(signature = (population_size, archive_size, minimize = true))]
+    pub fn new(population_size: usize, archive_size: usize, minimize: bool) -> PyResult<Self> {
+        if population_size == 0 || archive_size == 0 {
+            return Err(PyValueError::new_err("population_size and archive_size must be > 0"));
+        }
+
+        Ok(Self {
+            population_size,
+            archive_size,
+            minimize,
+            generation_counter: AtomicU64::new(0),
+            archive: Vec::new(),
+            archive_fitness: Vec::new(),
+        })
+    }
+
+    /// Get current generation
+    pub fn get_generation(&self) -> u64 {
+        self.generation_counter.load(Ordering::Relaxed)
+    }
+
+    /// The current non-dominated archive.
+    pub fn get_archive(&self) -> Vec<AgentDNA> {
+        self.archive.clone()
+    }
+
+    /// Run `generations` rounds of SPEA2 evolution and return the final
+    /// non-dominated archive. `objective_fns` are Python callables
+    /// `objective_fn(genes: List[float]) -> float`, one per objective.
+    pub fn evolve(
+        &mut self,
+        py: Python<'_>,
+        mut population: Vec<AgentDNA>,
+        objective_fns: Vec<PyObject>,
+        generations: u64,
+        crossover_rate: Float,
+        mutation_rate: Float,
+    ) -> PyResult<Vec<AgentDNA>> {
+        if population.is_empty() {
+            return Err(PyValueError::new_err("Population must not be empty"));
+        }
+        if objective_fns.is_empty() {
+            return Err(PyValueError::new_err("Need at least one objective function"));
+        }
+
+        for _ in 0..generations {
+            for agent in population.iter_mut().chain(self.archive.iter_mut()) {
+                if agent.objectives.len() != objective_fns.len() {
+                    agent.objectives = objective_fns
+                        .iter()
+                        .map(|f| f.call1(py, (agent.genes.clone(),))?.extract(py))
+                        .collect::<PyResult<Vec<Float>>>()?;
+                }
+            }
+
+            let combined: Vec<AgentDNA> = population.iter().chain(self.archive.iter()).cloned().collect();
+            let fitness = self.compute_fitness(&combined);
+            let (archive, archive_fitness) = self.environmental_select(combined, fitness);
+            self.archive = archive;
+            self.archive_fitness = archive_fitness;
+
+            let mut rng = thread_rng();
+            let mut parents1 = Vec::with_capacity(self.population_size);
+            let mut parents2 = Vec::with_capacity(self.population_size);
+            for _ in 0..self.population_size {
+                parents1.push(self.binary_tournament(&mut rng));
+                parents2.push(self.binary_tournament(&mut rng));
+            }
+
+            let offspring = parallel_crossover(parents1, parents2, crossover_rate)?;
+            population = parallel_mutation(offspring, mutation_rate, 0.1)?;
+
+            self.generation_counter.fetch_add(1, Ordering::Relaxed);
+        }
+
+        info!(
+            "Spea2Engine evolved {} generations, archive size {}",
+            generations,
+            self.archive.len()
+        );
+        Ok(self.archive.clone())
+    }
+}
+
+impl Spea2Engine {
+    /// `a` dominates `b` if it is no worse in every objective and strictly
+    /// better in at least one, respecting `self.minimize`.
+    fn dominates(&self, a: &[Float], b: &[Float]) -> bool {
+        let mut strictly_better = false;
+        for (x, y) in a.iter().zip(b.iter()) {
+            let worse = if self.minimize { x > y } else { x < y };
+            let better = if self.minimize { x < y } else { x > y };
+            if worse {
+                return false;
+            }
+            if better {
+                strictly_better = true;
+            }
+        }
+        strictly_better
+    }
+
+    /// Compute SPEA2 fitness `F(i) = R(i) + D(i)` for every individual in `pool`.
+    fn compute_fitness(&self, pool: &[AgentDNA]) -> Vec<Float> {
+        let n = pool.len();
+        let normalized = normalize_objectives(pool);
+
+        // Strength S(i): how many individuals i dominates.
+        let strength: Vec<Float> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != i && self.dominates(&pool[i].objectives, &pool[j].objectives))
+                    .count() as Float
+            })
+            .collect();
+
+        // Raw fitness R(i) = sum of S(j) over all j dominating i. Non-dominated
+        // individuals get R = 0.
+        let raw: Vec<Float> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != i && self.dominates(&pool[j].objectives, &pool[i].objectives))
+                    .map(|j| strength[j])
+                    .sum()
+            })
+            .collect();
+
+        // Density D(i) = 1 / (sigma_k + 2), k-th nearest neighbor in
+        // normalized objective space.
+        let k = (n as Float).sqrt().floor().max(1.0) as usize;
+        let density: Vec<Float> = (0..n)
+            .map(|i| {
+                let mut distances: Vec<Float> = (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| euclidean_distance(&normalized[i], &normalized[j]))
+                    .collect();
+                distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let sigma_k = distances.get(k.saturating_sub(1)).copied().unwrap_or(0.0);
+                1.0 / (sigma_k + 2.0)
+            })
+            .collect();
+
+        (0..n).map(|i| raw[i] + density[i]).collect()
+    }
+
+    /// Environmental selection: non-dominated individuals (`F < 1`) form the
+    /// new archive, truncated (by removing the most clustered individual) or
+    /// filled with the best-`F` dominated individuals to reach `archive_size`.
+    fn environmental_select(
+        &self,
+        pool: Vec<AgentDNA>,
+        fitness: Vec<Float>,
+    ) -> (Vec<AgentDNA>, Vec<Float>) {
+        let mut scored: Vec<(AgentDNA, Float)> = pool.into_iter().zip(fitness).collect();
+
+        let mut non_dominated: Vec<(AgentDNA, Float)> =
+            scored.iter().filter(|(_, f)| *f < 1.0).cloned().collect();
+
+        if non_dominated.len() > self.archive_size {
+            while non_dominated.len() > self.archive_size {
+                let remove_idx = closest_to_neighbors(&non_dominated);
+                non_dominated.remove(remove_idx);
+            }
+        } else if non_dominated.len() < self.archive_size {
+            scored.retain(|(_, f)| *f >= 1.0);
+            scored.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+            let need = self.archive_size - non_dominated.len();
+            non_dominated.extend(scored.into_iter().take(need));
+        }
+
+        non_dominated.into_iter().unzip()
+    }
+
+    /// Binary tournament over the archive by fitness (lower `F` wins).
+    fn binary_tournament(&self, rng: &mut impl Rng) -> AgentDNA {
+        let i = rng.gen_range(0..self.archive.len());
+        let j = rng.gen_range(0..self.archive.len());
+        if self.archive_fitness[i] <= self.archive_fitness[j] {
+            self.archive[i].clone()
+        } else {
+            self.archive[j].clone()
+        }
+    }
+}
+
+/// Min-max normalize each objective dimension across `pool` to `[0, 1]`.
+fn normalize_objectives(pool: &[AgentDNA]) -> Vec<Vec<Float>> {
+    if pool.is_empty() {
+        return Vec::new();
+    }
+    let dims = pool[0].objectives.len();
+    let mut mins = vec![Float::INFINITY; dims];
+    let mut maxs = vec![Float::NEG_INFINITY; dims];
+
+    for agent in pool {
+        for (d, v) in agent.objectives.iter().enumerate() {
+            mins[d] = mins[d].min(*v);
+            maxs[d] = maxs[d].max(*v);
+        }
+    }
+
+    pool.iter()
+        .map(|agent| {
+            agent
+                .objectives
+                .iter()
+                .enumerate()
+                .map(|(d, v)| {
+                    let range = maxs[d] - mins[d];
+                    if range > 0.0 { (v - mins[d]) / range } else { 0.0 }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn euclidean_distance(a: &[Float], b: &[Float]) -> Float {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum::<Float>().sqrt()
+}
+
+/// Index of the individual whose sorted distance-to-neighbors vector is
+/// lexicographically smallest (closest nearest neighbor, ties broken by the
+/// next-nearest, and so on).
+fn closest_to_neighbors(individuals: &[(AgentDNA, Float)]) -> usize {
+    let n = individuals.len();
+    let sorted_distances: Vec<Vec<Float>> = (0..n)
+        .map(|i| {
+            let mut d: Vec<Float> = (0..n)
+                .filter(|&j| j != i)
+                .map(|j| euclidean_distance(&individuals[i].0.objectives, &individuals[j].0.objectives))
+                .collect();
+            d.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            d
+        })
+        .collect();
+
+    let mut closest = 0;
+    for i in 1..n {
+        if lexicographically_smaller(&sorted_distances[i], &sorted_distances[closest]) {
+            closest = i;
+        }
+    }
+    closest
+}
+
+fn lexicographically_smaller(a: &[Float], b: &[Float]) -> bool {
+    for (x, y) in a.iter().zip(b.iter()) {
+        match x.partial_cmp(y).unwrap() {
+            std::cmp::Ordering::Less => return true,
+            std::cmp::Ordering::Greater => return false,
+            std::cmp::Ordering::Equal => continue,
+        }
+    }
+    false
+}
@@ -2,9 +2,13 @@
 //! 
 //! A Rust-based engine for genetic evolution with gradual feature introduction.
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use rand::prelude::*;
 use tracing::info;
 
+use types::{AgentDNA, Float};
+
 // Modules
 pub mod utils;
 pub mod types;
@@ -12,23 +16,176 @@ pub mod genetic;
 pub mod neural;
 pub mod agent;
 
-/// Simple evolution engine for testing
+/// Simple heuristic-weight-evolution engine: each individual is a weight
+/// vector kept L2-normalized after every mutation, so vectors stay
+/// comparable in magnitude across generations. A lighter-weight sibling of
+/// `genetic::GeneticEngine`, useful for evolving decision-network or
+/// cognitive-state weight vectors directly.
 #[pyclass]
 pub struct EvolutionEngine {
     population_size: usize,
+    tournament_size: usize,
+    mutation_strength: Float,
+    population: Vec<AgentDNA>,
+    generation: u64,
+    best_genome: Option<AgentDNA>,
 }
 
 #[pymethods]
 impl EvolutionEngine {
     #[new]
-    pub fn new(population_size: usize) -> Self {
-        Self { population_size }
+    #[pyo3(signature = (population_size, gene_count, tournament_size = 3, mutation_strength = 0.2))]
+    pub fn new(
+        population_size: usize,
+        gene_count: usize,
+        tournament_size: usize,
+        mutation_strength: Float,
+    ) -> PyResult<Self> {
+        if population_size == 0 || gene_count == 0 {
+            return Err(PyValueError::new_err("population_size and gene_count must be > 0"));
+        }
+
+        let population = (0..population_size)
+            .map(|_| {
+                let mut rng = thread_rng();
+                let genes: Vec<Float> = (0..gene_count).map(|_| rng.gen_range(-1.0..1.0)).collect();
+                AgentDNA::new(normalize(genes))
+            })
+            .collect();
+
+        info!("EvolutionEngine initialized with population size: {}", population_size);
+
+        Ok(Self {
+            population_size,
+            tournament_size: tournament_size.max(1),
+            mutation_strength,
+            population,
+            generation: 0,
+            best_genome: None,
+        })
     }
-    
+
     /// Get population size
     pub fn get_population_size(&self) -> usize {
         self.population_size
     }
+
+    /// Get current generation
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Best fitness seen so far, if any individual has been evaluated.
+    pub fn get_best_fitness(&self) -> Option<Float> {
+        self.best_genome.as_ref().map(AgentDNA::get_fitness)
+    }
+
+    /// The best individual seen so far, if any individual has been evaluated.
+    pub fn get_champion(&self) -> Option<AgentDNA> {
+        self.best_genome.clone()
+    }
+
+    /// Get a copy of the current population.
+    pub fn get_population(&self) -> Vec<AgentDNA> {
+        self.population.clone()
+    }
+
+    /// Evaluate every unevaluated individual with `fitness_fn(genes) -> float`
+    /// and update the tracked champion.
+    pub fn evaluate(&mut self, py: Python<'_>, fitness_fn: PyObject) -> PyResult<()> {
+        for individual in self.population.iter_mut() {
+            if individual.has_fitness() {
+                continue;
+            }
+            let score: Float = fitness_fn.call1(py, (individual.genes.clone(),))?.extract(py)?;
+            individual.set_fitness(score);
+
+            if self.best_genome.as_ref().map_or(true, |b| score > b.get_fitness()) {
+                self.best_genome = Some(individual.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluate the current population, then produce the next generation via
+    /// tournament selection, uniform crossover, and mutate-then-renormalize.
+    /// Returns the generation's best fitness.
+    pub fn step_generation(&mut self, py: Python<'_>, fitness_fn: PyObject) -> PyResult<Float> {
+        self.evaluate(py, fitness_fn)?;
+
+        let mut next_population = Vec::with_capacity(self.population_size);
+        while next_population.len() < self.population_size {
+            let parent1 = self.tournament_select();
+            let parent2 = self.tournament_select();
+            let mut child = crossover(&parent1, &parent2);
+            mutate_and_renormalize(&mut child.genes, self.mutation_strength);
+            child.generation = self.generation + 1;
+            next_population.push(child);
+        }
+
+        self.population = next_population;
+        self.generation += 1;
+
+        Ok(self.best_genome.as_ref().map_or(0.0, AgentDNA::get_fitness))
+    }
+}
+
+impl EvolutionEngine {
+    /// Sample `tournament_size` individuals and return the fittest.
+    fn tournament_select(&self) -> AgentDNA {
+        let mut rng = thread_rng();
+        (0..self.tournament_size)
+            .map(|_| &self.population[rng.gen_range(0..self.population.len())])
+            .max_by(|a, b| a.get_fitness().partial_cmp(&b.get_fitness()).unwrap())
+            .expect("population must not be empty")
+            .clone()
+    }
+}
+
+/// Uniform crossover of two parents' gene vectors.
+fn crossover(parent1: &AgentDNA, parent2: &AgentDNA) -> AgentDNA {
+    let mut rng = thread_rng();
+    let genes: Vec<Float> = parent1
+        .genes
+        .iter()
+        .zip(parent2.genes.iter())
+        .map(|(g1, g2)| if rng.gen::<bool>() { *g1 } else { *g2 })
+        .collect();
+
+    let mut child = AgentDNA::new(genes);
+    child.parent_ids = vec![parent1.id.clone(), parent2.id.clone()];
+    child
+}
+
+/// Perturb one random gene by a uniform offset in `[-strength, strength]`,
+/// then renormalize the whole vector to unit L2 norm so weight vectors stay
+/// comparable in magnitude across generations.
+fn mutate_and_renormalize(genes: &mut [Float], strength: Float) {
+    if genes.is_empty() {
+        return;
+    }
+
+    let mut rng = thread_rng();
+    let idx = rng.gen_range(0..genes.len());
+    genes[idx] += rng.gen_range(-strength..strength);
+
+    let norm = genes.iter().map(|g| g * g).sum::<Float>().sqrt();
+    if norm > 0.0 {
+        for gene in genes.iter_mut() {
+            *gene /= norm;
+        }
+    }
+}
+
+/// L2-normalize a gene vector so its magnitude is 1.0 (no-op on a zero vector).
+fn normalize(genes: Vec<Float>) -> Vec<Float> {
+    let norm = genes.iter().map(|g| g * g).sum::<Float>().sqrt();
+    if norm > 0.0 {
+        genes.into_iter().map(|g| g / norm).collect()
+    } else {
+        genes
+    }
 }
 
 /// Initialize the Rust engine with Python - evolving version
@@ -54,7 +211,7 @@ fn lore_engine(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Register agent system
     agent::register_agent_functions(py, m)?;
     
-    // Register the evolution engine (minimal for now)
+    // Register the evolution engine
     m.add_class::<EvolutionEngine>()?;
     
     // Add metadata
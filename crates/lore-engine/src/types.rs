@@ -0,0 +1,685 @@
+//! # Common Types and Data Structures
+//! 
+//! This module defines all shared types, constants, and data structures
+//! used across the Lore Engine for maximum type safety and performance.
+
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyValueError, PyRuntimeError, PyIOError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+use ordered_float::OrderedFloat;
+
+/// Type alias for high-precision floating point numbers
+pub type Float = f64;
+
+/// Type alias for ordered floats (can be used as HashMap keys)
+pub type OrderedFloat64 = OrderedFloat<f64>;
+
+/// Type alias for agent IDs
+pub type AgentId = Uuid;
+
+/// Type alias for generation numbers
+pub type Generation = u64;
+
+/// Type alias for fitness scores
+pub type Fitness = OrderedFloat64;
+
+/// Agent DNA representation with metadata
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentDNA {
+    #[pyo3(get, set)]
+    pub id: String,
+    
+    #[pyo3(get, set)]
+    pub genes: Vec<Float>,
+    
+    pub fitness: Option<Float>,
+    
+    #[pyo3(get, set)]
+    pub generation: u64,
+    
+    #[pyo3(get, set)]
+    pub parent_ids: Vec<String>,
+    
+    #[pyo3(get, set)]
+    pub creation_time: u64,
+    
+    #[pyo3(get, set)]
+    pub mutations: u32,
+
+    #[pyo3(get, set)]
+    pub metadata: HashMap<String, String>,
+
+    /// Per-objective scores for multi-objective optimization (e.g. SPEA2).
+    /// Empty for single-objective runs, which use `fitness` instead.
+    #[pyo3(get, set)]
+    pub objectives: Vec<Float>,
+}
+
+#[pymethods]
+impl AgentDNA {
+    #[new]
+    pub fn new(genes: Vec<Float>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            genes,
+            fitness: None,
+            generation: 0,
+            parent_ids: Vec::new(),
+            creation_time: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            mutations: 0,
+            metadata: HashMap::new(),
+            objectives: Vec::new(),
+        }
+    }
+    
+    /// Get the gene count
+    pub fn gene_count(&self) -> usize {
+        self.genes.len()
+    }
+    
+    /// Clone the DNA with a new ID
+    pub fn clone_with_new_id(&self) -> Self {
+        let mut clone = self.clone();
+        clone.id = Uuid::new_v4().to_string();
+        clone
+    }
+    
+    /// Update fitness score
+    pub fn set_fitness(&mut self, fitness: Float) {
+        self.fitness = Some(fitness);
+    }
+    
+    /// Check if DNA has valid fitness
+    pub fn has_fitness(&self) -> bool {
+        self.fitness.is_some()
+    }
+    
+    /// Get fitness or return default
+    pub fn get_fitness(&self) -> Float {
+        self.fitness.unwrap_or(0.0)
+    }
+    
+    /// Get fitness for Python
+    #[getter(fitness)]
+    pub fn get_fitness_py(&self) -> Option<Float> {
+        self.fitness
+    }
+    
+    /// Set fitness for Python
+    #[setter(fitness)]
+    pub fn set_fitness_py(&mut self, fitness: Float) {
+        self.fitness = Some(fitness);
+    }
+}
+
+/// Functional form of a [`RateSchedule`].
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RateScheduleKind {
+    Constant,
+    Linear,
+    Quadratic,
+    ExponentialDecay,
+}
+
+/// A rate (mutation or selection pressure) that varies over the course of an
+/// evolution run instead of staying fixed, following oxigen's
+/// `mutation_rate`/`slope_params` design. Construct via the `constant`,
+/// `linear`, `quadratic`, or `exponential_decay` static methods; `evaluate`
+/// computes the effective rate for a given generation, clamped to `bound`.
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateSchedule {
+    #[pyo3(get, set)]
+    pub kind: RateScheduleKind,
+
+    #[pyo3(get, set)]
+    pub start: Float,
+
+    /// Slope for `Linear`/`Quadratic`, decay constant for `ExponentialDecay`.
+    #[pyo3(get, set)]
+    pub coefficient: Float,
+
+    #[pyo3(get, set)]
+    pub bound: Float,
+}
+
+#[pymethods]
+impl RateSchedule {
+    #[staticmethod]
+    pub fn constant(value: Float) -> Self {
+        Self { kind: RateScheduleKind::Constant, start: value, coefficient: 0.0, bound: value }
+    }
+
+    #[staticmethod]
+    pub fn linear(start: Float, coefficient: Float, bound: Float) -> Self {
+        Self { kind: RateScheduleKind::Linear, start, coefficient, bound }
+    }
+
+    #[staticmethod]
+    pub fn quadratic(start: Float, coefficient: Float, bound: Float) -> Self {
+        Self { kind: RateScheduleKind::Quadratic, start, coefficient, bound }
+    }
+
+    #[staticmethod]
+    pub fn exponential_decay(start: Float, decay: Float, bound: Float) -> Self {
+        Self { kind: RateScheduleKind::ExponentialDecay, start, coefficient: decay, bound }
+    }
+
+    /// Compute the effective rate for `generation`, clamped to `bound`.
+    /// `progress` (0.0 at the start of the run, 1.0 at `max_generations`) and
+    /// `n_solutions_found` are accepted so future schedules can react to
+    /// stagnation, but the built-in forms only depend on `generation`.
+    pub fn evaluate(&self, generation: u64, progress: Float, n_solutions_found: u64) -> Float {
+        let _ = (progress, n_solutions_found);
+
+        let raw = match self.kind {
+            RateScheduleKind::Constant => self.start,
+            RateScheduleKind::Linear => self.start + self.coefficient * generation as Float,
+            RateScheduleKind::Quadratic => self.start + self.coefficient * (generation as Float).powi(2),
+            RateScheduleKind::ExponentialDecay => {
+                self.start * (-self.coefficient * generation as Float).exp()
+            }
+        };
+
+        let (lower, upper) = if self.bound >= self.start {
+            (self.start.min(self.bound), self.bound.max(self.start))
+        } else {
+            (self.bound, self.start)
+        };
+        raw.clamp(lower, upper)
+    }
+}
+
+/// Functional form of a [`SelectionStrategy`].
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SelectionStrategyKind {
+    Tournament,
+    RouletteWheel,
+    RankBased,
+    StochasticUniversalSampling,
+}
+
+/// Parent-selection strategy used by `GeneticEngine::evolve`, implemented in
+/// `genetic::selection`. Construct via the `tournament`, `roulette_wheel`,
+/// `rank_based`, or `stochastic_universal_sampling` static methods.
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectionStrategy {
+    #[pyo3(get, set)]
+    pub kind: SelectionStrategyKind,
+
+    /// Sample size for `Tournament`.
+    #[pyo3(get, set)]
+    pub tournament_size: usize,
+
+    /// Bias toward higher-ranked individuals for `RankBased`.
+    #[pyo3(get, set)]
+    pub pressure: Float,
+}
+
+#[pymethods]
+impl SelectionStrategy {
+    #[staticmethod]
+    pub fn tournament(size: usize) -> Self {
+        Self { kind: SelectionStrategyKind::Tournament, tournament_size: size, pressure: 0.0 }
+    }
+
+    #[staticmethod]
+    pub fn roulette_wheel() -> Self {
+        Self { kind: SelectionStrategyKind::RouletteWheel, tournament_size: 0, pressure: 0.0 }
+    }
+
+    #[staticmethod]
+    pub fn rank_based(pressure: Float) -> Self {
+        Self { kind: SelectionStrategyKind::RankBased, tournament_size: 0, pressure }
+    }
+
+    #[staticmethod]
+    pub fn stochastic_universal_sampling() -> Self {
+        Self {
+            kind: SelectionStrategyKind::StochasticUniversalSampling,
+            tournament_size: 0,
+            pressure: 0.0,
+        }
+    }
+}
+
+/// Evolution parameters for genetic algorithms
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvolutionParams {
+    #[pyo3(get, set)]
+    pub population_size: usize,
+    
+    #[pyo3(get, set)]
+    pub mutation_rate: Float,
+    
+    #[pyo3(get, set)]
+    pub crossover_rate: Float,
+    
+    #[pyo3(get, set)]
+    pub selection_pressure: Float,
+    
+    #[pyo3(get, set)]
+    pub elitism_count: usize,
+    
+    #[pyo3(get, set)]
+    pub max_generations: u64,
+    
+    #[pyo3(get, set)]
+    pub target_fitness: Option<Float>,
+    
+    #[pyo3(get, set)]
+    pub parallel_threads: Option<usize>,
+    
+    #[pyo3(get, set)]
+    pub tournament_size: usize,
+
+    /// Stop when the best fitness improves by less than `stagnation_epsilon`
+    /// over this many consecutive generations. `None` disables the check.
+    #[pyo3(get, set)]
+    pub stagnation_generations: Option<u64>,
+
+    /// Minimum best-fitness improvement over `stagnation_generations` below
+    /// which the run is considered stagnant.
+    #[pyo3(get, set)]
+    pub stagnation_epsilon: Float,
+
+    /// Stop once this many fitness evaluations have been performed.
+    /// `None` disables the check.
+    #[pyo3(get, set)]
+    pub max_evaluations: Option<u64>,
+
+    /// When set, overrides `mutation_rate` with a schedule evaluated each
+    /// generation in `GeneticEngine::evolve`.
+    #[pyo3(get, set)]
+    pub mutation_schedule: Option<RateSchedule>,
+
+    /// When set, overrides `selection_pressure` with a schedule evaluated
+    /// each generation in `GeneticEngine::evolve`.
+    #[pyo3(get, set)]
+    pub selection_schedule: Option<RateSchedule>,
+
+    /// Skip re-evaluating the fitness function for genomes already seen
+    /// (keyed on genes rounded to `cache_precision` decimals).
+    #[pyo3(get, set)]
+    pub enable_fitness_cache: bool,
+
+    /// Decimal places genes are rounded to when building the fitness cache key.
+    #[pyo3(get, set)]
+    pub cache_precision: u32,
+
+    /// Sharing radius (`sigma_share`) for fitness-sharing niching. `None`
+    /// disables niching and selection uses raw fitness.
+    #[pyo3(get, set)]
+    pub sharing_radius: Option<Float>,
+
+    /// Power term in the fitness-sharing function `sh(d) = 1 - (d/sigma_share)^alpha`.
+    #[pyo3(get, set)]
+    pub sharing_alpha: Float,
+
+    /// When set, overrides the default tournament selection (sized by
+    /// `tournament_size`) with this strategy.
+    #[pyo3(get, set)]
+    pub selection_strategy: Option<SelectionStrategy>,
+
+    /// Minimize the fitness function instead of maximizing it (e.g. for
+    /// cost/error functions). Inverts comparisons throughout selection,
+    /// elitism, and best-agent tracking.
+    #[pyo3(get, set)]
+    pub minimize: bool,
+}
+
+#[pymethods]
+impl EvolutionParams {
+    #[new]
+    #[pyo3(signature = (
+        population_size = 100,
+        mutation_rate = 0.1,
+        crossover_rate = 0.8,
+        selection_pressure = 0.7,
+        elitism_count = 5,
+        max_generations = 1000,
+        target_fitness = None,
+        parallel_threads = None,
+        tournament_size = 3,
+        stagnation_generations = None,
+        stagnation_epsilon = 1e-6,
+        max_evaluations = None,
+        mutation_schedule = None,
+        selection_schedule = None,
+        enable_fitness_cache = false,
+        cache_precision = 6,
+        sharing_radius = None,
+        sharing_alpha = 1.0,
+        selection_strategy = None,
+        minimize = false
+    ))]
+    pub fn new(
+        population_size: usize,
+        mutation_rate: Float,
+        crossover_rate: Float,
+        selection_pressure: Float,
+        elitism_count: usize,
+        max_generations: u64,
+        target_fitness: Option<Float>,
+        parallel_threads: Option<usize>,
+        tournament_size: usize,
+        stagnation_generations: Option<u64>,
+        stagnation_epsilon: Float,
+        max_evaluations: Option<u64>,
+        mutation_schedule: Option<RateSchedule>,
+        selection_schedule: Option<RateSchedule>,
+        enable_fitness_cache: bool,
+        cache_precision: u32,
+        sharing_radius: Option<Float>,
+        sharing_alpha: Float,
+        selection_strategy: Option<SelectionStrategy>,
+        minimize: bool,
+    ) -> Self {
+        Self {
+            population_size,
+            mutation_rate,
+            crossover_rate,
+            selection_pressure,
+            elitism_count,
+            max_generations,
+            target_fitness,
+            parallel_threads,
+            tournament_size,
+            stagnation_generations,
+            stagnation_epsilon,
+            max_evaluations,
+            mutation_schedule,
+            selection_schedule,
+            enable_fitness_cache,
+            cache_precision,
+            sharing_radius,
+            sharing_alpha,
+            selection_strategy,
+            minimize,
+        }
+    }
+    
+    /// Validate parameters for safety
+    pub fn validate(&self) -> PyResult<()> {
+        if self.population_size == 0 {
+            return Err(PyValueError::new_err("Population size must be > 0"));
+        }
+        if !(0.0..=1.0).contains(&self.mutation_rate) {
+            return Err(PyValueError::new_err("Mutation rate must be between 0.0 and 1.0"));
+        }
+        if !(0.0..=1.0).contains(&self.crossover_rate) {
+            return Err(PyValueError::new_err("Crossover rate must be between 0.0 and 1.0"));
+        }
+        if !(0.0..=1.0).contains(&self.selection_pressure) {
+            return Err(PyValueError::new_err("Selection pressure must be between 0.0 and 1.0"));
+        }
+        if self.elitism_count >= self.population_size {
+            return Err(PyValueError::new_err("Elitism count must be < population size"));
+        }
+        if self.tournament_size == 0 {
+            return Err(PyValueError::new_err("Tournament size must be > 0"));
+        }
+        Ok(())
+    }
+}
+
+/// Evolution result with detailed metrics
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct EvolutionResult {
+    #[pyo3(get)]
+    pub generation: u64,
+    
+    #[pyo3(get)]
+    pub best_fitness: Float,
+    
+    #[pyo3(get)]
+    pub average_fitness: Float,
+    
+    #[pyo3(get)]
+    pub fitness_std: Float,
+    
+    #[pyo3(get)]
+    pub convergence_rate: Float,
+    
+    #[pyo3(get)]
+    pub elapsed_ms: u64,
+    
+    #[pyo3(get)]
+    pub evaluations: u64,
+    
+    #[pyo3(get)]
+    pub best_agent: Option<Py<AgentDNA>>,
+    
+    #[pyo3(get)]
+    pub population: Vec<Py<AgentDNA>>,
+    
+    #[pyo3(get)]
+    pub diversity_index: Float,
+
+    #[pyo3(get)]
+    pub success: bool,
+
+    /// One entry per generation run, in order, for inspecting convergence
+    /// after the fact.
+    #[pyo3(get)]
+    pub history: Vec<GenerationStats>,
+}
+
+#[pymethods]
+impl EvolutionResult {
+    /// Render `history` as CSV (header + one row per generation) so Python
+    /// callers can plot convergence without re-deriving it from `history`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("generation,best_fitness,average_fitness,fitness_std,diversity_index,evaluations,elapsed_ms,mutation_rate\n");
+        for stats in &self.history {
+            csv.push_str(&format!(
+                "{},{:.6},{:.6},{:.6},{:.6},{},{},{:.6}\n",
+                stats.generation,
+                stats.best_fitness,
+                stats.average_fitness,
+                stats.fitness_std,
+                stats.diversity_index,
+                stats.evaluations,
+                stats.elapsed_ms,
+                stats.mutation_rate,
+            ));
+        }
+        csv
+    }
+}
+
+/// Per-generation snapshot of a `GeneticEngine::evolve` run, accumulated into
+/// `EvolutionResult.history` so long runs are observable after the fact
+/// instead of only exposing the final result.
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationStats {
+    #[pyo3(get)]
+    pub generation: u64,
+
+    #[pyo3(get)]
+    pub best_fitness: Float,
+
+    #[pyo3(get)]
+    pub average_fitness: Float,
+
+    #[pyo3(get)]
+    pub fitness_std: Float,
+
+    #[pyo3(get)]
+    pub diversity_index: Float,
+
+    #[pyo3(get)]
+    pub evaluations: u64,
+
+    #[pyo3(get)]
+    pub elapsed_ms: u64,
+
+    /// Effective mutation rate used for this generation (may come from a
+    /// `RateSchedule`).
+    #[pyo3(get)]
+    pub mutation_rate: Float,
+}
+
+/// Neural network node representation
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeuralNode {
+    #[pyo3(get, set)]
+    pub id: String,
+    
+    #[pyo3(get, set)]
+    pub weights: Vec<Float>,
+    
+    #[pyo3(get, set)]
+    pub bias: Float,
+    
+    #[pyo3(get, set)]
+    pub activation: String,
+    
+    #[pyo3(get, set)]
+    pub connections: Vec<String>,
+    
+    #[pyo3(get, set)]
+    pub layer: usize,
+}
+
+/// Social network graph metrics
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkMetrics {
+    #[pyo3(get)]
+    pub node_count: usize,
+    
+    #[pyo3(get)]
+    pub edge_count: usize,
+    
+    #[pyo3(get)]
+    pub density: Float,
+    
+    #[pyo3(get)]
+    pub clustering_coefficient: Float,
+    
+    #[pyo3(get)]
+    pub average_path_length: Float,
+    
+    #[pyo3(get)]
+    pub diameter: usize,
+    
+    #[pyo3(get)]
+    pub components: usize,
+    
+    #[pyo3(get)]
+    pub modularity: Float,
+    
+    #[pyo3(get)]
+    pub small_world_coefficient: Float,
+}
+
+/// Performance profiling result
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileResult {
+    #[pyo3(get)]
+    pub function_name: String,
+    
+    #[pyo3(get)]
+    pub elapsed_ns: u64,
+    
+    #[pyo3(get)]
+    pub memory_peak_kb: u64,
+    
+    #[pyo3(get)]
+    pub cpu_usage_percent: Float,
+    
+    #[pyo3(get)]
+    pub thread_count: usize,
+    
+    #[pyo3(get)]
+    pub iterations: u64,
+    
+    #[pyo3(get)]
+    pub throughput_ops_per_sec: Float,
+}
+
+/// Error types for robust error handling
+#[derive(thiserror::Error, Debug)]
+pub enum LoreError {
+    #[error("Genetic algorithm error: {0}")]
+    Genetic(String),
+    
+    #[error("Neural network error: {0}")]
+    Neural(String),
+    
+    #[error("Agent simulation error: {0}")]
+    Agent(String),
+    
+    #[error("Performance error: {0}")]
+    Performance(String),
+    
+    #[error("Validation error: {0}")]
+    Validation(String),
+    
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    
+    #[error("JSON serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Binary serialization error: {0}")]
+    Bincode(#[from] bincode::Error),
+}
+
+impl From<LoreError> for PyErr {
+    fn from(err: LoreError) -> PyErr {
+        match err {
+            LoreError::Genetic(msg) => PyRuntimeError::new_err(format!("Genetic: {}", msg)),
+            LoreError::Neural(msg) => PyRuntimeError::new_err(format!("Neural: {}", msg)),
+            LoreError::Agent(msg) => PyRuntimeError::new_err(format!("Agent: {}", msg)),
+            LoreError::Performance(msg) => PyRuntimeError::new_err(format!("Performance: {}", msg)),
+            LoreError::Validation(msg) => PyValueError::new_err(format!("Validation: {}", msg)),
+            LoreError::Io(err) => PyIOError::new_err(format!("IO: {}", err)),
+            LoreError::Json(err) => PyValueError::new_err(format!("JSON: {}", err)),
+            LoreError::Bincode(err) => PyValueError::new_err(format!("Bincode: {}", err)),
+        }
+    }
+}
+
+/// Result type for all operations
+pub type LoreResult<T> = Result<T, LoreError>;
+
+/// Register all types with Python module
+pub fn register_types(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<AgentDNA>()?;
+    m.add_class::<RateScheduleKind>()?;
+    m.add_class::<RateSchedule>()?;
+    m.add_class::<SelectionStrategyKind>()?;
+    m.add_class::<SelectionStrategy>()?;
+    m.add_class::<EvolutionParams>()?;
+    m.add_class::<EvolutionResult>()?;
+    m.add_class::<GenerationStats>()?;
+    m.add_class::<NeuralNode>()?;
+    m.add_class::<NetworkMetrics>()?;
+    m.add_class::<ProfileResult>()?;
+    
+    // Add constants
+    m.add("DEFAULT_POPULATION_SIZE", 100)?;
+    m.add("DEFAULT_MUTATION_RATE", 0.1)?;
+    m.add("DEFAULT_CROSSOVER_RATE", 0.8)?;
+    m.add("DEFAULT_SELECTION_PRESSURE", 0.7)?;
+    m.add("MAX_GENE_COUNT", 10000)?;
+    m.add("MIN_POPULATION_SIZE", 10)?;
+    
+    Ok(())
+}
@@ -84,6 +84,44 @@ impl PerformanceCounter {
     }
 }
 
+/// Tracking `#[global_allocator]` wrapper: counts bytes allocated/deallocated
+/// and the high-water mark of bytes in use, so `snapshot()` can report real
+/// numbers instead of zeros. Gated behind the `memory_tracking` feature so
+/// callers doing heavy `batch_forward` runs can opt out of the per-alloc
+/// atomic overhead.
+#[cfg(feature = "memory_tracking")]
+mod tracking_allocator {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    pub static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+    pub static DEALLOCATED: AtomicU64 = AtomicU64::new(0);
+    pub static PEAK_USAGE: AtomicU64 = AtomicU64::new(0);
+
+    pub struct TrackingAllocator;
+
+    unsafe impl GlobalAlloc for TrackingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                let allocated = ALLOCATED.fetch_add(layout.size() as u64, Ordering::Relaxed) + layout.size() as u64;
+                let deallocated = DEALLOCATED.load(Ordering::Relaxed);
+                PEAK_USAGE.fetch_max(allocated.saturating_sub(deallocated), Ordering::Relaxed);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            DEALLOCATED.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(feature = "memory_tracking")]
+#[global_allocator]
+static GLOBAL_ALLOCATOR: tracking_allocator::TrackingAllocator = tracking_allocator::TrackingAllocator;
+
 /// Memory usage information
 #[pyclass]
 #[derive(Debug, Clone)]
@@ -111,6 +149,30 @@ impl MemoryInfo {
     }
 }
 
+/// Sample the global allocator's counters into a `MemoryInfo`. Requires the
+/// `memory_tracking` feature; without it, the allocator isn't wrapped and
+/// every field reads zero.
+#[pyfunction]
+pub fn snapshot() -> MemoryInfo {
+    #[cfg(feature = "memory_tracking")]
+    {
+        use std::sync::atomic::Ordering;
+        let allocated_bytes = tracking_allocator::ALLOCATED.load(Ordering::Relaxed);
+        let deallocated_bytes = tracking_allocator::DEALLOCATED.load(Ordering::Relaxed);
+        MemoryInfo {
+            allocated_bytes,
+            deallocated_bytes,
+            current_usage: allocated_bytes.saturating_sub(deallocated_bytes),
+            peak_usage: tracking_allocator::PEAK_USAGE.load(Ordering::Relaxed),
+        }
+    }
+
+    #[cfg(not(feature = "memory_tracking"))]
+    {
+        MemoryInfo::new()
+    }
+}
+
 /// Get system information
 #[pyfunction]
 pub fn get_system_info() -> PyResult<String> {
@@ -152,6 +214,7 @@ pub fn register_util_functions(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Timer>()?;
     m.add_class::<PerformanceCounter>()?;
     m.add_class::<MemoryInfo>()?;
+    m.add_function(wrap_pyfunction!(snapshot, m)?)?;
     m.add_function(wrap_pyfunction!(get_system_info, m)?)?;
     m.add_function(wrap_pyfunction!(benchmark_function, m)?)?;
     Ok(())